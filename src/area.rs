@@ -5,11 +5,13 @@ use rand::{thread_rng, Rng};
 use std::fmt;
 
 // Internal includes.
+use super::intersects_shape::rasterized_intersects_shape;
 use super::{
-    Containment, ContainsLocalPosition, ContainsPosition, HasArea, HasHeight, HasPosition, HasSize,
-    HasWidth, IntersectsLocalPosition, IntersectsPosition, IsArea, IsPosition, IsSize, Length,
-    Placed, PlacedObject, PlacedShape, Position, ProvidesArea, ProvidesPlacedShape,
-    ProvidesPosition, ProvidesSize, Shape, Size,
+    Containment, ContainsLocalPosition, ContainsPosition, Coord, CountsTiles, HasArea, HasHeight,
+    HasPosition, HasSize, HasWidth, IntersectsLocalPosition, IntersectsPlacedShape,
+    IntersectsPosition, IntersectsShape, IsArea, IsPosition, IsSize, Length, Placed, PlacedObject,
+    PlacedShape, Position, ProvidesArea, ProvidesPlacedShape, ProvidesPosition, ProvidesSize,
+    Shape, Size, TileCount,
 };
 
 /// Defines an `Area` by a [`Position`](struct.Position.html) and [`Size`](struct.Size.html).
@@ -37,6 +39,34 @@ impl Area {
     pub fn new(position: Position, size: Size) -> Self {
         Self { position, size }
     }
+
+    /// Returns the `Area` shared by both `self` and `other`, or `None` if they do not overlap.
+    ///
+    /// ```
+    /// # use dungen_minion_geometry::*;
+    /// let a = Area::new(Position::new(0, 0), Size::new(4, 4));
+    /// let b = Area::new(Position::new(2, 3), Size::new(4, 4));
+    /// let c = Area::new(Position::new(4, 0), Size::new(4, 4));
+    ///
+    /// assert!(a.intersection(&b) == Some(Area::new(Position::new(2, 3), Size::new(2, 1))));
+    /// assert!(a.intersection(&c) == None);
+    /// ```
+    pub fn intersection(&self, other: &Area) -> Option<Area> {
+        let left = self.position.x().max(other.position.x());
+        let top = self.position.y().max(other.position.y());
+        let right = (self.position.x() + self.width() as Coord)
+            .min(other.position.x() + other.width() as Coord);
+        let bottom = (self.position.y() + self.height() as Coord)
+            .min(other.position.y() + other.height() as Coord);
+        if left >= right || top >= bottom {
+            None
+        } else {
+            Some(Area::new(
+                Position::new(left, top),
+                Size::new((right - left) as Length, (bottom - top) as Length),
+            ))
+        }
+    }
 }
 
 impl ContainsLocalPosition for Area {
@@ -64,6 +94,15 @@ impl ContainsLocalPosition for Area {
 
 impl ContainsPosition for Area {}
 
+impl CountsTiles for Area {
+    fn count_tiles(&self) -> TileCount {
+        let width = self.width() as usize;
+        let height = self.height() as usize;
+        let interior = width.saturating_sub(2) * height.saturating_sub(2);
+        TileCount::new(interior, (width * height) - interior)
+    }
+}
+
 impl fmt::Display for Area {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "( ( {} ), ( {} ) )", self.position, self.size)
@@ -115,8 +154,22 @@ impl IntersectsLocalPosition for Area {
     }
 }
 
+impl IntersectsPlacedShape for Area {
+    fn intersects_placed_shape(&self, placed_shape: &dyn PlacedShape) -> bool {
+        // Every tile within an `Area` intersects it, so the other `PlacedShape` can test the `Area` by its own means.
+        placed_shape.intersects_shape(self.position(), self)
+    }
+}
+
 impl IntersectsPosition for Area {}
 
+impl IntersectsShape for Area {
+    fn intersects_shape(&self, other_offset: &Position, other_shape: &dyn Shape) -> bool {
+        // Every tile within an `Area` intersects it, so only the other `Shape` needs testing.
+        rasterized_intersects_shape(self, |_| true, other_offset, other_shape)
+    }
+}
+
 impl IsArea for Area {}
 
 impl IsSize for Area {}
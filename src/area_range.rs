@@ -60,10 +60,7 @@ impl AreaRange {
 }
 
 impl Distribution<Area> for AreaRange {
-    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Area
-    where
-        R: Rng,
-    {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Area {
         Area::new(rng.sample(self.position_range), rng.sample(self.size_range))
     }
 }
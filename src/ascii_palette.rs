@@ -0,0 +1,68 @@
+// External includes.
+
+// Standard includes.
+
+// Internal includes.
+use super::Containment;
+
+/// Defines the character used for each [`Containment`](enum.Containment.html) when rendering shapes as ASCII art, or parsing shapes from it.
+///
+/// The default palette draws disjoint tiles as `' '`, edge tiles as `'#'`, and interior tiles as `'.'`.
+/// ```
+/// # use dungen_minion_geometry::*;
+/// let palette = AsciiPalette::default();
+/// assert!(palette.character(Containment::Intersects) == '#');
+/// assert!(palette.containment('.') == Some(Containment::Contains));
+/// assert!(palette.containment('x') == None);
+///
+/// let palette = AsciiPalette::new('~', 'W', 'f');
+/// assert!(palette.character(Containment::Disjoint) == '~');
+/// assert!(palette.containment('W') == Some(Containment::Intersects));
+/// ```
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct AsciiPalette {
+    disjoint: char,
+    intersects: char,
+    contains: char,
+}
+
+impl AsciiPalette {
+    /// Creates a new `AsciiPalette` from the characters for disjoint, edge, and interior tiles.
+    pub fn new(disjoint: char, intersects: char, contains: char) -> Self {
+        Self {
+            disjoint,
+            intersects,
+            contains,
+        }
+    }
+
+    /// Returns the character used for the given `Containment`.
+    pub fn character(&self, containment: Containment) -> char {
+        match containment {
+            Containment::Disjoint => self.disjoint,
+            Containment::Intersects => self.intersects,
+            Containment::Contains => self.contains,
+        }
+    }
+
+    /// Returns the `Containment` the given character stands for, or `None` if the character is not part of this palette.
+    ///
+    /// If several `Containment`s share a character, the character is read as the first of `Disjoint`, `Intersects`, and `Contains` which uses it.
+    pub fn containment(&self, character: char) -> Option<Containment> {
+        if character == self.disjoint {
+            Some(Containment::Disjoint)
+        } else if character == self.intersects {
+            Some(Containment::Intersects)
+        } else if character == self.contains {
+            Some(Containment::Contains)
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for AsciiPalette {
+    fn default() -> Self {
+        Self::new(' ', '#', '.')
+    }
+}
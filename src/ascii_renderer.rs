@@ -0,0 +1,143 @@
+// External includes.
+
+// Standard includes.
+
+// Internal includes.
+use super::{
+    Area, AsciiPalette, Containment, HasBottom, HasHeight, HasLeft, HasRight, HasTop, HasWidth,
+    PlacedShape, Position, Size,
+};
+
+/// Renders several [`PlacedShape`](trait.PlacedShape.html)s as layers of one piece of ASCII art, with an optional legend.
+///
+/// The art covers every layer's [`Area`](struct.Area.html), in world coordinates. Each tile is drawn with the [`AsciiPalette`](struct.AsciiPalette.html) of the last layer added which intersects it; tiles no layer intersects are drawn with the background character. The disjoint character of each layer's palette is not used.
+///
+/// The legend follows the art, with one line per layer, in the order the layers were added; the layer's edge and interior characters, a space, and the layer's name.
+/// ```
+/// # use dungen_minion_geometry::*;
+/// let mut renderer = AsciiRenderer::new(' ');
+/// renderer.add_layer(
+///     "room",
+///     Box::new(Area::new(Position::new(0, 0), Size::new(6, 4))),
+///     AsciiPalette::default(),
+/// );
+/// renderer.add_layer(
+///     "pool",
+///     Box::new(Area::new(Position::new(2, 1), Size::new(2, 2))),
+///     AsciiPalette::new(' ', '~', '~'),
+/// );
+/// renderer.add_layer(
+///     "corridor",
+///     Box::new(Area::new(Position::new(6, 1), Size::new(3, 2))),
+///     AsciiPalette::new(' ', '+', '+'),
+/// );
+///
+/// assert!(
+///     renderer.render(false)
+///         == concat!(
+///             "######   \n",
+///             "#.~~.#+++\n",
+///             "#.~~.#+++\n",
+///             "######   \n",
+///         )
+/// );
+/// assert!(renderer.render(true).ends_with("#. room\n~~ pool\n++ corridor\n"));
+///
+/// // With no layers, there is nothing to draw.
+/// assert!(AsciiRenderer::new(' ').render(false).is_empty());
+/// ```
+#[derive(Clone)]
+pub struct AsciiRenderer {
+    background: char,
+    layers: Vec<(String, Box<dyn PlacedShape>, AsciiPalette)>,
+}
+
+impl AsciiRenderer {
+    /// Creates a new `AsciiRenderer` with no layers, which draws tiles no layer intersects with the given background character.
+    pub fn new(background: char) -> Self {
+        Self {
+            background,
+            layers: Vec::new(),
+        }
+    }
+
+    /// Adds a named `PlacedShape` on top of the existing layers, to be drawn with the given `AsciiPalette`.
+    pub fn add_layer(
+        &mut self,
+        name: &str,
+        placed_shape: Box<dyn PlacedShape>,
+        palette: AsciiPalette,
+    ) {
+        self.layers.push((name.to_string(), placed_shape, palette));
+    }
+
+    /// Returns the `Area` covered by the rendered art; the smallest `Area` containing every layer's `Area`.
+    pub fn area(&self) -> Area {
+        let mut areas = self
+            .layers
+            .iter()
+            .map(|(_, placed_shape, _)| *placed_shape.area())
+            .filter(|area| area.width() > 0 && area.height() > 0);
+        let first = match areas.next() {
+            Some(first) => first,
+            None => return Area::new(Position::zero(), Size::zero()),
+        };
+
+        let (left, top, right, bottom) = areas.fold(
+            (first.left(), first.top(), first.right(), first.bottom()),
+            |(left, top, right, bottom), area| {
+                (
+                    left.min(area.left()),
+                    top.min(area.top()),
+                    right.max(area.right()),
+                    bottom.max(area.bottom()),
+                )
+            },
+        );
+        let mut area = Area::new(Position::new(left, top), Size::zero());
+        area.right_set(right);
+        area.bottom_set(bottom);
+
+        area
+    }
+
+    /// Returns the layers as ASCII art, followed by the legend if `include_legend` is true.
+    ///
+    /// Every line, including the last, ends with `'\n'`.
+    pub fn render(&self, include_legend: bool) -> String {
+        let area = self.area();
+        let mut output = String::new();
+        if area.width() > 0 && area.height() > 0 {
+            for y in area.top()..=area.bottom() {
+                for x in area.left()..=area.right() {
+                    let position = Position::new(x, y);
+                    let character = self
+                        .layers
+                        .iter()
+                        .rev()
+                        .find_map(|(_, placed_shape, palette)| {
+                            match placed_shape.contains_position(position) {
+                                Containment::Disjoint => None,
+                                containment => Some(palette.character(containment)),
+                            }
+                        })
+                        .unwrap_or(self.background);
+                    output.push(character);
+                }
+                output.push('\n');
+            }
+        }
+
+        if include_legend {
+            for (name, _, palette) in self.layers.iter() {
+                output.push(palette.character(Containment::Intersects));
+                output.push(palette.character(Containment::Contains));
+                output.push(' ');
+                output.push_str(name);
+                output.push('\n');
+            }
+        }
+
+        output
+    }
+}
@@ -0,0 +1,397 @@
+// External includes.
+
+// Standard includes.
+use std::hash::{Hash, Hasher};
+
+// Internal includes.
+use super::containment::containment_from_neighbours;
+use super::intersects_shape::rasterized_intersects_shape;
+use super::{
+    Area, AsciiPalette, Containment, ContainsLocalPosition, ContainsPosition, Coord, CountsTiles,
+    HasArea, HasHeight, HasPosition, HasSize, HasWidth, IntersectsLocalPosition,
+    IntersectsPlacedShape, IntersectsPosition, IntersectsShape, IsArea, IsPosition, IsSize,
+    IteratesLocalTiles, Length, ParseAsciiError, Placed, PlacedObject, PlacedShape, Position,
+    ProvidesArea, ProvidesPlacedShape, ProvidesSize, Shape, Size, TileCount,
+};
+
+const BITS_PER_WORD: usize = 64;
+
+/// A [`PlacedShape`](trait.PlacedShape.html) stored as packed bits; one bit per tile for whether the tile intersects the shape, and one bit per tile for whether the shape contains the tile.
+///
+/// Rasterizing another `PlacedShape` into a `BitmaskShape` evaluates it once, after which every query is a constant-time lookup. Tiles can be edited individually after creation. The bits cover the [`Area`](struct.Area.html) of the `BitmaskShape`; everything outside of it is disjoint. Changing the [`Size`](struct.Size.html) of the `Area` resizes the bits; tiles added to the right or bottom are disjoint, and tiles cut from the right or bottom are lost.
+/// ```
+/// # use dungen_minion_geometry::*;
+/// let room = Area::new(Position::new(0, 0), Size::new(9, 9));
+/// let pillar = Oval::new(Position::new(2, 2), Size::new(5, 5));
+/// let room_with_pillar =
+///     BooleanPlacedShape::new(BooleanOperation::Difference, Box::new(room), Box::new(pillar));
+///
+/// let mut bitmask = BitmaskShape::from_placed_shape(&room_with_pillar);
+/// assert!(*bitmask.area() == *room_with_pillar.area());
+/// for y in -1..=9 {
+///     for x in -1..=9 {
+///         let position = Position::new(x, y);
+///         assert!(bitmask.contains_position(position) == room_with_pillar.contains_position(position));
+///     }
+/// }
+///
+/// // Knock a hole through the outer wall.
+/// bitmask.set_containment(Position::new(0, 4), Containment::Disjoint);
+/// assert!(bitmask.contains_position(Position::new(0, 4)) == Containment::Disjoint);
+/// // The tile next to the hole is still marked as contained...
+/// assert!(bitmask.contains_position(Position::new(1, 4)) == Containment::Contains);
+/// // ...until the containment is recalculated.
+/// bitmask.recalculate_containment();
+/// assert!(bitmask.contains_position(Position::new(1, 4)) == Containment::Intersects);
+///
+/// // Edits outside of the bitmask's area are ignored.
+/// bitmask.set_containment(Position::new(20, 20), Containment::Contains);
+/// assert!(bitmask.contains_position(Position::new(20, 20)) == Containment::Disjoint);
+///
+/// // A new bitmask starts empty.
+/// let mut bitmask = BitmaskShape::new(Area::new(Position::new(5, 5), Size::new(3, 3)));
+/// assert!(bitmask.tiles().count() == 0);
+/// for y in 0..3 {
+///     for x in 0..3 {
+///         bitmask.set_local_containment(Position::new(x, y), Containment::Intersects);
+///     }
+/// }
+/// bitmask.recalculate_containment();
+/// assert!(bitmask.contains_position(Position::new(6, 6)) == Containment::Contains);
+/// assert!(bitmask.edge_tiles().count() == 8);
+///
+/// // Growing the bitmask adds disjoint tiles, and shrinking it loses tiles.
+/// let mut resized = bitmask.clone();
+/// *resized.size_mut() = Size::new(10, 10);
+/// assert!(resized.tiles().eq(bitmask.tiles()));
+/// assert!(resized.count_tiles() == bitmask.count_tiles());
+/// *resized.size_mut() = Size::new(2, 2);
+/// assert!(resized.tiles().count() == 4);
+/// *resized.size_mut() = Size::new(3, 3);
+/// assert!(resized.tiles().count() == 4);
+/// assert!(resized.contains_position(Position::new(7, 7)) == Containment::Disjoint);
+/// ```
+#[derive(Clone, Debug)]
+pub struct BitmaskShape {
+    area: Area,
+    bits_size: Size,
+    intersects: Box<[u64]>,
+    contains: Box<[u64]>,
+}
+
+impl BitmaskShape {
+    /// Creates a new, empty `BitmaskShape` covering the given `Area`.
+    pub fn new(area: Area) -> Self {
+        Self {
+            area,
+            bits_size: *area.size(),
+            intersects: Self::new_bits(*area.size()),
+            contains: Self::new_bits(*area.size()),
+        }
+    }
+
+    /// Creates a new `BitmaskShape` by rasterizing the given `PlacedShape` within its `Area`.
+    pub fn from_placed_shape<TPlacedShape>(placed_shape: &TPlacedShape) -> Self
+    where
+        TPlacedShape: PlacedShape + ?Sized,
+    {
+        let mut output = Self::new(*placed_shape.area());
+        for (position, containment) in placed_shape.local_tiles() {
+            output.set_local_containment(position, containment);
+        }
+
+        output
+    }
+
+    /// Creates a new `BitmaskShape` at the given `Position` by parsing ASCII art drawn with the given `AsciiPalette`.
+    ///
+    /// Each line of the text is a row of tiles, and each character is a tile. The `BitmaskShape` is as wide as the longest line; shorter lines are treated as padded with disjoint tiles. The `Containment` of each tile is taken as drawn.
+    /// ```
+    /// # use dungen_minion_geometry::*;
+    /// let text = concat!(
+    ///     "#####\n",
+    ///     "#...#\n",
+    ///     "##.##\n",
+    ///     " ###\n",
+    /// );
+    /// let prefab = BitmaskShape::from_ascii(text, Position::new(10, 20), &AsciiPalette::default()).unwrap();
+    /// assert!(*prefab.area() == Area::new(Position::new(10, 20), Size::new(5, 4)));
+    /// assert!(prefab.contains_position(Position::new(12, 22)) == Containment::Contains);
+    /// assert!(prefab.contains_position(Position::new(10, 23)) == Containment::Disjoint);
+    /// assert!(prefab.contains_position(Position::new(14, 23)) == Containment::Disjoint);
+    ///
+    /// let error = BitmaskShape::from_ascii("#.#\n#?#\n", Position::new(0, 0), &AsciiPalette::default());
+    /// assert!(
+    ///     error
+    ///         == Err(ParseAsciiError::UnknownCharacter {
+    ///             character: '?',
+    ///             position: Position::new(1, 1)
+    ///         })
+    /// );
+    /// ```
+    pub fn from_ascii(
+        text: &str,
+        position: Position,
+        palette: &AsciiPalette,
+    ) -> Result<Self, ParseAsciiError> {
+        let width = text
+            .lines()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        let height = text.lines().count();
+        let mut output = Self::new(Area::new(
+            position,
+            Size::new(width as Length, height as Length),
+        ));
+        for (y, line) in text.lines().enumerate() {
+            for (x, character) in line.chars().enumerate() {
+                let local_position = Position::new(x as Coord, y as Coord);
+                match palette.containment(character) {
+                    Some(containment) => output.set_local_containment(local_position, containment),
+                    None => {
+                        return Err(ParseAsciiError::UnknownCharacter {
+                            character,
+                            position: local_position,
+                        })
+                    }
+                }
+            }
+        }
+
+        Ok(output)
+    }
+
+    /// Sets the `Containment` of the tile at the given local `Position`.
+    ///
+    /// Positions outside of the `BitmaskShape` are ignored. The `Containment` of neighbouring tiles is not changed; see [`recalculate_containment`](#method.recalculate_containment).
+    pub fn set_local_containment(&mut self, position: Position, containment: Containment) {
+        self.fit_bits_to_area();
+        if let Some(index) = self.index(position) {
+            let word = index / BITS_PER_WORD;
+            let bit = 1 << (index % BITS_PER_WORD);
+            if containment == Containment::Disjoint {
+                self.intersects[word] &= !bit;
+            } else {
+                self.intersects[word] |= bit;
+            }
+            if containment == Containment::Contains {
+                self.contains[word] |= bit;
+            } else {
+                self.contains[word] &= !bit;
+            }
+        }
+    }
+
+    /// Sets the `Containment` of the tile at the given `Position`.
+    ///
+    /// Positions outside of the `BitmaskShape` are ignored. The `Containment` of neighbouring tiles is not changed; see [`recalculate_containment`](#method.recalculate_containment).
+    pub fn set_containment(&mut self, position: Position, containment: Containment) {
+        self.set_local_containment(position - *self.position(), containment);
+    }
+
+    /// Recalculates the `Containment` of every intersecting tile from the tiles around it.
+    ///
+    /// An intersecting tile is contained if all eight of its neighbours also intersect, and is on the edge of the shape otherwise.
+    pub fn recalculate_containment(&mut self) {
+        self.fit_bits_to_area();
+        for y in 0..self.bits_size.height() as Coord {
+            for x in 0..self.bits_size.width() as Coord {
+                let position = Position::new(x, y);
+                let containment = containment_from_neighbours(position, |position| {
+                    self.intersects_local_position(position)
+                });
+                if containment != Containment::Disjoint {
+                    let index = self.index(position).unwrap();
+                    let bit = 1 << (index % BITS_PER_WORD);
+                    if containment == Containment::Contains {
+                        self.contains[index / BITS_PER_WORD] |= bit;
+                    } else {
+                        self.contains[index / BITS_PER_WORD] &= !bit;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Resizes the bits to the `Size` of the `Area`, if it has changed, keeping the tiles within both.
+    fn fit_bits_to_area(&mut self) {
+        let size = *self.area.size();
+        if size == self.bits_size {
+            return;
+        }
+
+        let mut output = Self::new(self.area);
+        for (position, containment) in self.local_tiles() {
+            output.set_local_containment(position, containment);
+        }
+        *self = output;
+    }
+
+    // usize::div_ceil needs a newer compiler than this crate supports.
+    #[allow(clippy::manual_div_ceil)]
+    fn new_bits(size: Size) -> Box<[u64]> {
+        let bit_count = size.width() as usize * size.height() as usize;
+        vec![0; (bit_count + BITS_PER_WORD - 1) / BITS_PER_WORD].into_boxed_slice()
+    }
+
+    /// Returns the index of the bit for the given local `Position`, or `None` if it is outside of the bits or the `Area`.
+    ///
+    /// The bits may not yet fit a changed `Area`, in which case only the tiles within both are used.
+    fn index(&self, position: Position) -> Option<usize> {
+        if position.x() < 0
+            || position.y() < 0
+            || position.x() as usize >= self.bits_size.width().min(self.area.width()) as usize
+            || position.y() as usize >= self.bits_size.height().min(self.area.height()) as usize
+        {
+            None
+        } else {
+            Some((position.y() as usize * self.bits_size.width() as usize) + position.x() as usize)
+        }
+    }
+
+    fn is_bit_set(bits: &[u64], index: usize) -> bool {
+        (bits[index / BITS_PER_WORD] >> (index % BITS_PER_WORD)) & 1 == 1
+    }
+}
+
+impl ContainsLocalPosition for BitmaskShape {
+    fn contains_local_position(&self, position: Position) -> Containment {
+        match self.index(position) {
+            Some(index) if Self::is_bit_set(&self.contains, index) => Containment::Contains,
+            Some(index) if Self::is_bit_set(&self.intersects, index) => Containment::Intersects,
+            _ => Containment::Disjoint,
+        }
+    }
+}
+
+impl ContainsPosition for BitmaskShape {}
+
+impl CountsTiles for BitmaskShape {
+    fn count_tiles(&self) -> TileCount {
+        if self.bits_size != *self.area.size() {
+            let interior = self
+                .local_tiles()
+                .filter(|(_, containment)| *containment == Containment::Contains)
+                .count();
+            return TileCount::new(interior, self.local_tiles().count() - interior);
+        }
+
+        let count_ones =
+            |bits: &[u64]| -> usize { bits.iter().map(|word| word.count_ones() as usize).sum() };
+        let interior = count_ones(&self.contains);
+        TileCount::new(interior, count_ones(&self.intersects) - interior)
+    }
+}
+
+impl HasArea for BitmaskShape {
+    fn area(&self) -> &Area {
+        &self.area
+    }
+
+    fn area_mut(&mut self) -> &mut Area {
+        self.fit_bits_to_area();
+        &mut self.area
+    }
+}
+
+impl HasPosition for BitmaskShape {
+    fn position(&self) -> &Position {
+        self.area.position()
+    }
+
+    fn position_mut(&mut self) -> &mut Position {
+        self.area.position_mut()
+    }
+}
+
+impl HasSize for BitmaskShape {
+    fn size(&self) -> &Size {
+        self.area.size()
+    }
+
+    fn size_mut(&mut self) -> &mut Size {
+        self.fit_bits_to_area();
+        self.area.size_mut()
+    }
+}
+
+impl Eq for BitmaskShape {}
+
+impl Hash for BitmaskShape {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.area.hash(state);
+        for (position, containment) in self.local_tiles() {
+            position.hash(state);
+            (containment == Containment::Contains).hash(state);
+        }
+    }
+}
+
+impl IntersectsLocalPosition for BitmaskShape {
+    fn intersects_local_position(&self, position: Position) -> bool {
+        match self.index(position) {
+            Some(index) => Self::is_bit_set(&self.intersects, index),
+            None => false,
+        }
+    }
+}
+
+impl IntersectsPlacedShape for BitmaskShape {}
+
+impl IntersectsPosition for BitmaskShape {}
+
+impl IntersectsShape for BitmaskShape {
+    fn intersects_shape(&self, other_offset: &Position, other_shape: &dyn Shape) -> bool {
+        rasterized_intersects_shape(
+            self.area(),
+            |position| self.intersects_position(position),
+            other_offset,
+            other_shape,
+        )
+    }
+}
+
+impl IsArea for BitmaskShape {}
+
+impl IsSize for BitmaskShape {}
+
+impl Placed for BitmaskShape {}
+
+impl PlacedObject for BitmaskShape {}
+
+impl PartialEq for BitmaskShape {
+    fn eq(&self, other: &Self) -> bool {
+        if self.bits_size == *self.area.size() && other.bits_size == *other.area.size() {
+            self.area == other.area
+                && self.intersects == other.intersects
+                && self.contains == other.contains
+        } else {
+            self.area == other.area && self.local_tiles().eq(other.local_tiles())
+        }
+    }
+}
+
+impl ProvidesArea for BitmaskShape {
+    fn provide_area(&self) -> Area {
+        *self.area()
+    }
+}
+
+impl ProvidesPlacedShape for BitmaskShape {
+    fn provide_placed_shape(&self) -> Box<dyn PlacedShape> {
+        Box::new(self.clone())
+    }
+}
+
+impl ProvidesSize for BitmaskShape {
+    fn provide_size(&self) -> Size {
+        *self.area.size()
+    }
+}
+
+impl Shape for BitmaskShape {
+    fn box_shape_clone(&self) -> Box<dyn Shape> {
+        Box::new((*self).clone())
+    }
+}
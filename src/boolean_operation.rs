@@ -0,0 +1,20 @@
+// External includes.
+
+// Standard includes.
+
+// Internal includes.
+
+/// Defines how a [`BooleanPlacedShape`](struct.BooleanPlacedShape.html) combines its two [`PlacedShape`](trait.PlacedShape.html)s.
+///
+/// The edge tiles of a shape are treated as its walls; cutting one shape out of another keeps the edge tiles of the cut.
+#[derive(Copy, Clone, Debug, Display, Eq, Hash, PartialEq)]
+pub enum BooleanOperation {
+    /// Tiles in either shape.
+    Union,
+    /// Tiles in both shapes.
+    Intersection,
+    /// Tiles in the first shape which are not in the interior of the second shape.
+    Difference,
+    /// Tiles in either shape which are not in the interior of the other shape.
+    SymmetricDifference,
+}
@@ -0,0 +1,266 @@
+// External includes.
+
+// Standard includes.
+
+// Internal includes.
+use super::{
+    Area, BitmaskShape, BooleanOperation, Containment, ContainsLocalPosition, ContainsPosition,
+    CountsTiles, HasArea, HasBottom, HasLeft, HasPosition, HasRight, HasSize, HasTop,
+    IntersectsLocalPosition, IntersectsPlacedShape, IntersectsPosition, IntersectsShape, IsArea,
+    IsPosition, IsSize, Placed, PlacedObject, PlacedShape, Position, ProvidesArea,
+    ProvidesPlacedShape, ProvidesSize, Shape, Size, TileCount,
+};
+
+/// Combines two [`PlacedShape`](trait.PlacedShape.html)s with a [`BooleanOperation`](enum.BooleanOperation.html), and implements `PlacedShape` for the result.
+///
+/// As a `BooleanPlacedShape` is itself a `PlacedShape`, operations can be nested arbitrarily. A tile reports [`Containment::Contains`](enum.Containment.html) only if all eight of its neighbours are part of the result, so the seams between shapes are classified correctly, and an edge created by a cut reports `Containment::Intersects`. The bounding [`Area`](struct.Area.html) is shrunk to fit the result; the result is bounded by that `Area`.
+///
+/// The result is rasterized once, into a [`BitmaskShape`](struct.BitmaskShape.html), when it is created, so nested operations are as quick to query as a single one.
+/// ```
+/// # use dungen_minion_geometry::*;
+/// let room = Area::new(Position::new(0, 0), Size::new(7, 7));
+/// let pillar = Area::new(Position::new(2, 2), Size::new(3, 3));
+/// let room_with_pillar =
+///     BooleanPlacedShape::new(BooleanOperation::Difference, Box::new(room), Box::new(pillar));
+///
+/// // The interior of the pillar is cut out of the room, and the edges of the cut are walls.
+/// assert!(room_with_pillar.contains_position(Position::new(3, 3)) == Containment::Disjoint);
+/// assert!(room_with_pillar.contains_position(Position::new(2, 3)) == Containment::Intersects);
+/// assert!(room_with_pillar.contains_position(Position::new(1, 1)) == Containment::Contains);
+/// assert!(*room_with_pillar.area() == room);
+///
+/// // Overlapping rooms leave no wall where they overlap.
+/// let west = Area::new(Position::new(0, 0), Size::new(5, 3));
+/// let east = Area::new(Position::new(3, 0), Size::new(5, 3));
+/// let hall = BooleanPlacedShape::new(BooleanOperation::Union, Box::new(west), Box::new(east));
+/// assert!(hall.contains_position(Position::new(3, 1)) == Containment::Contains);
+/// assert!(hall.contains_position(Position::new(4, 1)) == Containment::Contains);
+/// assert!(*hall.area() == Area::new(Position::new(0, 0), Size::new(8, 3)));
+///
+/// let overlap =
+///     BooleanPlacedShape::new(BooleanOperation::Intersection, Box::new(west), Box::new(east));
+/// assert!(*overlap.area() == Area::new(Position::new(3, 0), Size::new(2, 3)));
+///
+/// // Only the parts of each wing outside of the other wing's interior remain.
+/// let west_wing = Area::new(Position::new(0, 0), Size::new(6, 3));
+/// let east_wing = Area::new(Position::new(3, 0), Size::new(6, 3));
+/// let wings = BooleanPlacedShape::new(
+///     BooleanOperation::SymmetricDifference,
+///     Box::new(west_wing),
+///     Box::new(east_wing),
+/// );
+/// assert!(wings.contains_position(Position::new(3, 1)) == Containment::Intersects);
+/// assert!(wings.contains_position(Position::new(4, 1)) == Containment::Disjoint);
+/// assert!(wings.contains_position(Position::new(5, 1)) == Containment::Intersects);
+///
+/// // Cutting away half of a shape shrinks the bounding area to fit.
+/// let half_hall = BooleanPlacedShape::new(
+///     BooleanOperation::Difference,
+///     Box::new(hall),
+///     Box::new(Area::new(Position::new(4, -1), Size::new(6, 5))),
+/// );
+/// assert!(*half_hall.area() == Area::new(Position::new(0, 0), Size::new(5, 3)));
+/// assert!(half_hall.contains_position(Position::new(4, 1)) == Containment::Intersects);
+///
+/// // Moving the result moves everything it was built from.
+/// let mut moved_room = room_with_pillar.clone();
+/// *moved_room.position_mut() = Position::new(10, 10);
+/// assert!(moved_room.contains_position(Position::new(13, 13)) == Containment::Disjoint);
+/// assert!(moved_room.contains_position(Position::new(11, 11)) == Containment::Contains);
+///
+/// // Deeply nested operations stay quick to build and query.
+/// let mut nested = BooleanPlacedShape::new(
+///     BooleanOperation::Union,
+///     Box::new(Area::new(Position::new(0, 0), Size::new(40, 40))),
+///     Box::new(Area::new(Position::new(0, 0), Size::new(40, 40))),
+/// );
+/// for depth in 0..16 {
+///     let pillar = Area::new(Position::new(2 + (depth * 2), 2 + (depth * 2)), Size::new(3, 3));
+///     nested = BooleanPlacedShape::new(
+///         BooleanOperation::Difference,
+///         Box::new(Area::new(Position::new(0, 0), Size::new(40, 40))),
+///         Box::new(BooleanPlacedShape::new(
+///             BooleanOperation::Difference,
+///             Box::new(Area::new(Position::new(0, 0), Size::new(40, 40))),
+///             Box::new(BooleanPlacedShape::new(
+///                 BooleanOperation::Union,
+///                 Box::new(nested),
+///                 Box::new(pillar),
+///             )),
+///         )),
+///     );
+/// }
+/// assert!(nested.count_tiles().total() > 0);
+/// ```
+#[derive(Clone, Debug)]
+pub struct BooleanPlacedShape {
+    bitmask: BitmaskShape,
+    operation: BooleanOperation,
+}
+
+impl BooleanPlacedShape {
+    /// Creates a new `BooleanPlacedShape` which combines the `left` and `right` `PlacedShape`s with the given `BooleanOperation`.
+    pub fn new(
+        operation: BooleanOperation,
+        left: Box<dyn PlacedShape>,
+        right: Box<dyn PlacedShape>,
+    ) -> Self {
+        let left_top = Position::new(left.left().min(right.left()), left.top().min(right.top()));
+        let mut candidate_area = Area::new(left_top, Size::zero());
+        candidate_area.right_set(left.right().max(right.right()));
+        candidate_area.bottom_set(left.bottom().max(right.bottom()));
+
+        let mut tiles = Vec::new();
+        for y in candidate_area.top()..=candidate_area.bottom() {
+            for x in candidate_area.left()..=candidate_area.right() {
+                let position = Position::new(x, y);
+                if intersects_shapes_position(operation, left.as_ref(), right.as_ref(), position) {
+                    tiles.push(position);
+                }
+            }
+        }
+
+        let area = if tiles.is_empty() {
+            Area::new(left_top, Size::zero())
+        } else {
+            let left = tiles.iter().map(|tile| tile.x()).min().unwrap();
+            let top = tiles.iter().map(|tile| tile.y()).min().unwrap();
+            let mut area = Area::new(Position::new(left, top), Size::zero());
+            area.right_set(tiles.iter().map(|tile| tile.x()).max().unwrap());
+            area.bottom_set(tiles.iter().map(|tile| tile.y()).max().unwrap());
+            area
+        };
+
+        let mut bitmask = BitmaskShape::new(area);
+        for tile in tiles.iter() {
+            bitmask.set_containment(*tile, Containment::Intersects);
+        }
+        bitmask.recalculate_containment();
+
+        Self { bitmask, operation }
+    }
+
+    /// Returns the `BooleanOperation` used to combine the two `PlacedShape`s.
+    pub fn operation(&self) -> BooleanOperation {
+        self.operation
+    }
+}
+
+/// Returns true if the given `Position` is part of the result of combining the `left` and `right` `PlacedShape`s.
+fn intersects_shapes_position(
+    operation: BooleanOperation,
+    left: &dyn PlacedShape,
+    right: &dyn PlacedShape,
+    position: Position,
+) -> bool {
+    match operation {
+        BooleanOperation::Union => {
+            left.intersects_position(position) || right.intersects_position(position)
+        }
+        BooleanOperation::Intersection => {
+            left.intersects_position(position) && right.intersects_position(position)
+        }
+        BooleanOperation::Difference => {
+            left.intersects_position(position)
+                && right.contains_position(position) != Containment::Contains
+        }
+        BooleanOperation::SymmetricDifference => {
+            let left = left.contains_position(position);
+            let right = right.contains_position(position);
+            (left != Containment::Disjoint && right != Containment::Contains)
+                || (right != Containment::Disjoint && left != Containment::Contains)
+        }
+    }
+}
+
+impl ContainsLocalPosition for BooleanPlacedShape {
+    fn contains_local_position(&self, position: Position) -> Containment {
+        self.bitmask.contains_local_position(position)
+    }
+}
+
+impl ContainsPosition for BooleanPlacedShape {}
+
+impl CountsTiles for BooleanPlacedShape {
+    fn count_tiles(&self) -> TileCount {
+        self.bitmask.count_tiles()
+    }
+}
+
+impl HasArea for BooleanPlacedShape {
+    fn area(&self) -> &Area {
+        self.bitmask.area()
+    }
+
+    fn area_mut(&mut self) -> &mut Area {
+        self.bitmask.area_mut()
+    }
+}
+
+impl HasPosition for BooleanPlacedShape {
+    fn position(&self) -> &Position {
+        self.bitmask.position()
+    }
+
+    fn position_mut(&mut self) -> &mut Position {
+        self.bitmask.position_mut()
+    }
+}
+
+impl HasSize for BooleanPlacedShape {
+    fn size(&self) -> &Size {
+        self.bitmask.size()
+    }
+
+    fn size_mut(&mut self) -> &mut Size {
+        self.bitmask.size_mut()
+    }
+}
+
+impl IntersectsLocalPosition for BooleanPlacedShape {
+    fn intersects_local_position(&self, position: Position) -> bool {
+        self.bitmask.intersects_local_position(position)
+    }
+}
+
+impl IntersectsPlacedShape for BooleanPlacedShape {}
+
+impl IntersectsPosition for BooleanPlacedShape {}
+
+impl IntersectsShape for BooleanPlacedShape {
+    fn intersects_shape(&self, other_offset: &Position, other_shape: &dyn Shape) -> bool {
+        self.bitmask.intersects_shape(other_offset, other_shape)
+    }
+}
+
+impl IsArea for BooleanPlacedShape {}
+
+impl IsSize for BooleanPlacedShape {}
+
+impl Placed for BooleanPlacedShape {}
+
+impl PlacedObject for BooleanPlacedShape {}
+
+impl ProvidesArea for BooleanPlacedShape {
+    fn provide_area(&self) -> Area {
+        *self.area()
+    }
+}
+
+impl ProvidesPlacedShape for BooleanPlacedShape {
+    fn provide_placed_shape(&self) -> Box<dyn PlacedShape> {
+        Box::new(self.clone())
+    }
+}
+
+impl ProvidesSize for BooleanPlacedShape {
+    fn provide_size(&self) -> Size {
+        *self.size()
+    }
+}
+
+impl Shape for BooleanPlacedShape {
+    fn box_shape_clone(&self) -> Box<dyn Shape> {
+        Box::new((*self).clone())
+    }
+}
@@ -0,0 +1,187 @@
+// External includes.
+
+// Standard includes.
+
+// Internal includes.
+use super::containment::containment_from_neighbours;
+use super::intersects_shape::rasterized_intersects_shape;
+use super::{
+    Area, Containment, ContainsLocalPosition, ContainsPosition, Coord, CountsTiles, HasArea,
+    HasHeight, HasPosition, HasSize, HasWidth, IntersectsLocalPosition, IntersectsPlacedShape,
+    IntersectsPosition, IntersectsShape, IsArea, IsPosition, IsSize, Oval, Placed, PlacedObject,
+    PlacedShape, Position, ProvidesArea, ProvidesPlacedShape, ProvidesSize, Shape, Size,
+};
+
+/// Defines a capsule; an [`Area`](struct.Area.html) with rounded ends along its longer axis.
+///
+/// The ends are the halves of an [`Oval`](struct.Oval.html) as wide as the capsule is thick, and the middle is stretched out between them. A capsule is horizontal if its width is at least its height, and vertical otherwise; a square capsule is an `Oval`. As with `Oval`, a tile is contained only if all eight of its neighbours intersect.
+/// ```
+/// # use dungen_minion_geometry::*;
+/// let hall = Capsule::new(Area::new(Position::new(0, 0), Size::new(12, 5)));
+/// assert!(
+///     hall.render_ascii(&AsciiPalette::default())
+///         == concat!(
+///             " ########## \n",
+///             "##........##\n",
+///             "#..........#\n",
+///             "##........##\n",
+///             " ########## \n",
+///         )
+/// );
+///
+/// // Tall capsules are rounded at the top and bottom.
+/// let chapel = Capsule::new(Area::new(Position::new(0, 0), Size::new(5, 12)));
+/// for (position, containment) in hall.tiles() {
+///     let transposed = Position::new(position.y(), position.x());
+///     assert!(chapel.contains_position(transposed) == containment);
+/// }
+///
+/// // The ends of a capsule match an `Oval`, and a square capsule is an `Oval`.
+/// for thickness in 0..12 {
+///     for length in thickness..(thickness + 8) {
+///         let capsule = Capsule::new(Area::new(Position::new(0, 0), Size::new(length, thickness)));
+///         let oval = Oval::new(Position::new(0, 0), Size::new(thickness, thickness));
+///         let offset = Position::new((length - thickness) as Coord, 0);
+///         for (position, containment) in oval.tiles() {
+///             if position.x() < (thickness / 2) as Coord {
+///                 assert!(capsule.contains_position(position) == containment);
+///             } else if position.x() >= ((thickness + 1) / 2) as Coord {
+///                 assert!(capsule.contains_position(position + offset) == containment);
+///             }
+///         }
+///         if length == thickness {
+///             assert!(capsule.tiles().eq(oval.tiles()));
+///         }
+///     }
+/// }
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Capsule {
+    area: Area,
+}
+
+impl Capsule {
+    /// Creates a new `Capsule` filling the `Area`.
+    pub fn new(area: Area) -> Self {
+        Self { area }
+    }
+}
+
+impl ContainsLocalPosition for Capsule {
+    fn contains_local_position(&self, position: Position) -> Containment {
+        containment_from_neighbours(position, |position| {
+            self.intersects_local_position(position)
+        })
+    }
+}
+
+impl ContainsPosition for Capsule {}
+
+impl CountsTiles for Capsule {}
+
+impl HasArea for Capsule {
+    fn area(&self) -> &Area {
+        &self.area
+    }
+
+    fn area_mut(&mut self) -> &mut Area {
+        &mut self.area
+    }
+}
+
+impl HasPosition for Capsule {
+    fn position(&self) -> &Position {
+        self.area.position()
+    }
+
+    fn position_mut(&mut self) -> &mut Position {
+        self.area.position_mut()
+    }
+}
+
+impl HasSize for Capsule {
+    fn size(&self) -> &Size {
+        self.area.size()
+    }
+
+    fn size_mut(&mut self) -> &mut Size {
+        self.area.size_mut()
+    }
+}
+
+impl IntersectsLocalPosition for Capsule {
+    fn intersects_local_position(&self, position: Position) -> bool {
+        if !self.area.intersects_local_position(position) {
+            return false;
+        }
+
+        // Work along the long axis, as though the capsule were horizontal.
+        let is_horizontal = self.width() >= self.height();
+        let (length, thickness, along, across) = if is_horizontal {
+            (self.width(), self.height(), position.x(), position.y())
+        } else {
+            (self.height(), self.width(), position.y(), position.x())
+        };
+
+        // Map the tile onto an `Oval` as wide as the capsule is thick, stretching its middle.
+        let half = (thickness as Coord - 1) / 2;
+        let from_end = length as Coord - 1 - along;
+        let along = if along <= half {
+            along
+        } else if from_end <= half {
+            thickness as Coord - 1 - from_end
+        } else {
+            half
+        };
+
+        let oval = Oval::new(Position::zero(), Size::new(thickness, thickness));
+        oval.intersects_local_position(Position::new(along, across))
+    }
+}
+
+impl IntersectsPlacedShape for Capsule {}
+
+impl IntersectsPosition for Capsule {}
+
+impl IntersectsShape for Capsule {
+    fn intersects_shape(&self, other_offset: &Position, other_shape: &dyn Shape) -> bool {
+        rasterized_intersects_shape(
+            self.area(),
+            |position| self.intersects_position(position),
+            other_offset,
+            other_shape,
+        )
+    }
+}
+
+impl IsArea for Capsule {}
+
+impl IsSize for Capsule {}
+
+impl Placed for Capsule {}
+
+impl PlacedObject for Capsule {}
+
+impl ProvidesArea for Capsule {
+    fn provide_area(&self) -> Area {
+        *self.area()
+    }
+}
+
+impl ProvidesPlacedShape for Capsule {
+    fn provide_placed_shape(&self) -> Box<dyn PlacedShape> {
+        Box::new(*self)
+    }
+}
+
+impl ProvidesSize for Capsule {
+    fn provide_size(&self) -> Size {
+        *self.area.size()
+    }
+}
+
+impl Shape for Capsule {
+    fn box_shape_clone(&self) -> Box<dyn Shape> {
+        Box::new(*self)
+    }
+}
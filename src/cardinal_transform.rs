@@ -0,0 +1,217 @@
+// External includes.
+
+// Standard includes.
+use std::convert::From;
+use std::ops::{Add, AddAssign, Mul, Neg};
+
+// Internal includes.
+use super::{
+    Area, CardinalDirection, CardinalRotation, HasBottom, HasPosition, HasRight, HasSize,
+    IsPosition, Position, Size,
+};
+
+/// Defines one of the eight symmetries of a square grid; a [`CardinalRotation`](enum.CardinalRotation.html), optionally preceded by a horizontal mirroring.
+///
+/// Applying a `CardinalTransform` to a [`Position`](struct.Position.html) first mirrors the position horizontally, if the transform is mirrored, and then rotates it as by `Position * CardinalRotation`. Transforms are composed with `+`, where `a + b` applies `a` and then `b`.
+/// ```
+/// # use dungen_minion_geometry::*;
+/// let position = Position::new(3, -2);
+///
+/// // Mirroring horizontally negates x; mirroring vertically negates y.
+/// assert!(position * CardinalTransform::MIRROR_HORIZONTAL == Position::new(-3, -2));
+/// assert!(position * CardinalTransform::MIRROR_VERTICAL == Position::new(3, 2));
+///
+/// // Unmirrored transforms are rotations.
+/// let right90 = CardinalTransform::from(CardinalRotation::Right90);
+/// assert!(position * right90 == position * CardinalRotation::Right90);
+///
+/// // Composition applies the left-hand transform first.
+/// for a in CardinalTransform::all().iter() {
+///     for b in CardinalTransform::all().iter() {
+///         assert!(position * (*a + *b) == (position * *a) * *b);
+///     }
+///
+///     // Every transform has an inverse.
+///     assert!(*a + -*a == CardinalTransform::IDENTITY);
+///     assert!((position * *a) * -*a == position);
+/// }
+///
+/// // Mirroring both horizontally and vertically is a half-turn.
+/// assert!(
+///     CardinalTransform::MIRROR_HORIZONTAL + CardinalTransform::MIRROR_VERTICAL
+///         == CardinalTransform::from(CardinalRotation::Full180)
+/// );
+///
+/// // Directions and sizes can be transformed, too.
+/// assert!(CardinalDirection::East * CardinalTransform::MIRROR_HORIZONTAL == CardinalDirection::West);
+/// assert!(CardinalDirection::North * CardinalTransform::MIRROR_HORIZONTAL == CardinalDirection::North);
+/// assert!(Size::new(4, 2) * right90 == Size::new(2, 4));
+///
+/// // Areas are transformed around the origin.
+/// let area = Area::new(Position::new(1, 2), Size::new(4, 3));
+/// assert!(
+///     area * CardinalTransform::MIRROR_HORIZONTAL
+///         == Area::new(Position::new(-4, 2), Size::new(4, 3))
+/// );
+/// assert!(area * right90 == Area::new(Position::new(2, -4), Size::new(3, 4)));
+/// ```
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CardinalTransform {
+    is_mirrored: bool,
+    rotation: CardinalRotation,
+}
+
+impl CardinalTransform {
+    /// Creates a new `CardinalTransform` which mirrors horizontally if `is_mirrored` is true, and then rotates by `rotation`.
+    pub fn new(is_mirrored: bool, rotation: CardinalRotation) -> Self {
+        Self {
+            is_mirrored,
+            rotation,
+        }
+    }
+
+    /// The transform which leaves everything unchanged.
+    pub const IDENTITY: CardinalTransform = Self {
+        is_mirrored: false,
+        rotation: CardinalRotation::None,
+    };
+
+    /// The transform which mirrors horizontally, negating the x-component of a `Position`.
+    pub const MIRROR_HORIZONTAL: CardinalTransform = Self {
+        is_mirrored: true,
+        rotation: CardinalRotation::None,
+    };
+
+    /// The transform which mirrors vertically, negating the y-component of a `Position`.
+    pub const MIRROR_VERTICAL: CardinalTransform = Self {
+        is_mirrored: true,
+        rotation: CardinalRotation::Full180,
+    };
+
+    /// Returns all eight `CardinalTransform`s; the four rotations, followed by the four mirrored rotations.
+    pub fn all() -> [CardinalTransform; 8] {
+        [
+            Self::new(false, CardinalRotation::None),
+            Self::new(false, CardinalRotation::Right90),
+            Self::new(false, CardinalRotation::Full180),
+            Self::new(false, CardinalRotation::Left90),
+            Self::new(true, CardinalRotation::None),
+            Self::new(true, CardinalRotation::Right90),
+            Self::new(true, CardinalRotation::Full180),
+            Self::new(true, CardinalRotation::Left90),
+        ]
+    }
+
+    /// Returns the transform which undoes this transform.
+    pub fn inverse(self) -> Self {
+        if self.is_mirrored {
+            // A mirrored transform undoes itself.
+            self
+        } else {
+            Self::new(false, CardinalRotation::None - self.rotation)
+        }
+    }
+
+    /// Returns true if this transform mirrors horizontally before it rotates.
+    pub fn is_mirrored(self) -> bool {
+        self.is_mirrored
+    }
+
+    /// Returns the `CardinalRotation` this transform applies after any mirroring.
+    pub fn rotation(self) -> CardinalRotation {
+        self.rotation
+    }
+}
+
+impl Add<CardinalRotation> for CardinalTransform {
+    type Output = CardinalTransform;
+
+    fn add(self, other: CardinalRotation) -> Self::Output {
+        self + CardinalTransform::from(other)
+    }
+}
+
+impl Add<CardinalTransform> for CardinalTransform {
+    type Output = CardinalTransform;
+
+    fn add(self, other: CardinalTransform) -> Self::Output {
+        // Mirroring after a rotation is the same as rotating the other way after mirroring.
+        let rotation = if other.is_mirrored {
+            CardinalRotation::None - self.rotation
+        } else {
+            self.rotation
+        };
+        Self::new(
+            self.is_mirrored != other.is_mirrored,
+            rotation + other.rotation,
+        )
+    }
+}
+
+impl AddAssign<CardinalTransform> for CardinalTransform {
+    fn add_assign(&mut self, other: CardinalTransform) {
+        *self = *self + other
+    }
+}
+
+impl From<CardinalRotation> for CardinalTransform {
+    fn from(rotation: CardinalRotation) -> Self {
+        Self::new(false, rotation)
+    }
+}
+
+impl Mul<CardinalTransform> for Area {
+    type Output = Self;
+
+    /// Returns the smallest `Area` covering every tile of `self` after a `CardinalTransform` around the origin.
+    fn mul(self, transform: CardinalTransform) -> Self::Output {
+        let top_left = *self.position() * transform;
+        let bottom_right = Position::new(self.right(), self.bottom()) * transform;
+        Area::new(
+            Position::new(
+                top_left.x().min(bottom_right.x()),
+                top_left.y().min(bottom_right.y()),
+            ),
+            *self.size() * transform,
+        )
+    }
+}
+
+impl Mul<CardinalTransform> for CardinalDirection {
+    type Output = Self;
+
+    fn mul(self, transform: CardinalTransform) -> Self::Output {
+        Option::<CardinalDirection>::from(Position::from(self) * transform)
+            .expect("A transformed cardinal direction is always a cardinal direction.")
+    }
+}
+
+impl Mul<CardinalTransform> for Position {
+    type Output = Self;
+
+    fn mul(self, transform: CardinalTransform) -> Self::Output {
+        let mirrored = if transform.is_mirrored {
+            Position::new(-self.x(), self.y())
+        } else {
+            self
+        };
+        mirrored * transform.rotation
+    }
+}
+
+impl Mul<CardinalTransform> for Size {
+    type Output = Self;
+
+    fn mul(self, transform: CardinalTransform) -> Self::Output {
+        // Mirroring does not change a size.
+        self * transform.rotation
+    }
+}
+
+impl Neg for CardinalTransform {
+    type Output = CardinalTransform;
+
+    fn neg(self) -> Self::Output {
+        self.inverse()
+    }
+}
@@ -0,0 +1,259 @@
+// External includes.
+use rand::distributions::Distribution;
+use rand::{thread_rng, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+// Standard includes.
+
+// Internal includes.
+use super::{
+    Area, BitmaskShape, Connectivity, Containment, Coord, Count, HasHeight, HasWidth, IsPosition,
+    PlacedShape, Position, ProvidesPlacedShape, RegionRetention,
+};
+
+/// Generates caves within an [`Area`](struct.Area.html), by smoothing random noise with a cellular automaton.
+///
+/// Each tile of the `Area` starts open with the fill probability. Each pass, a closed tile opens if its number of open neighbours is one of the birth counts, and an open tile stays open if its number of open neighbours is one of the survival counts.
+///
+/// The eight tiles sharing a side or a corner are neighbours, and tiles outside of the `Area` are closed. After the last pass, the regions of open tiles which share sides are kept as set by the [`RegionRetention`](enum.RegionRetention.html).
+///
+/// The cave is a [`BitmaskShape`](struct.BitmaskShape.html) over the `Area`, where a tile is contained only if all eight of its neighbours are open. Caves are generated by sampling the `CaveGenerator` with an `Rng`, or from a seed.
+/// ```
+/// # use dungen_minion_geometry::*;
+/// use rand::{thread_rng, Rng};
+/// use std::collections::HashSet;
+///
+/// let area = Area::new(Position::new(-20, -10), Size::new(40, 20));
+/// let generator = CaveGenerator::new(area, 0.55, &[5, 6, 7, 8], &[4, 5, 6, 7, 8], 4, RegionRetention::Largest);
+///
+/// // The same seed always generates the same cave.
+/// let cave = generator.generate_from_seed(7);
+/// assert!(cave == generator.generate_from_seed(7));
+/// assert!(*cave.area() == area);
+///
+/// // Tiles next to the outside of the cave are its walls.
+/// for (position, containment) in cave.tiles() {
+///     let is_wall = Connectivity::Eight
+///         .offsets()
+///         .iter()
+///         .any(|offset| !cave.intersects_position(position + *offset));
+///     assert!(is_wall == (containment == Containment::Intersects));
+/// }
+///
+/// // Only the largest region is kept; every tile can be reached from every other.
+/// let tiles: HashSet<Position> = cave.tiles().map(|(position, _)| position).collect();
+/// let start = *tiles.iter().next().unwrap();
+/// let mut reached = HashSet::new();
+/// let mut stack = vec![start];
+/// while let Some(position) = stack.pop() {
+///     if tiles.contains(&position) && reached.insert(position) {
+///         stack.extend(Connectivity::Four.offsets().iter().map(|offset| position + *offset));
+///     }
+/// }
+/// assert!(reached == tiles);
+///
+/// // Keeping every region keeps the largest region as well.
+/// let every_region = CaveGenerator::new(area, 0.55, &[5, 6, 7, 8], &[4, 5, 6, 7, 8], 4, RegionRetention::All)
+///     .generate_from_seed(7);
+/// assert!(tiles.iter().all(|position| every_region.intersects_position(*position)));
+///
+/// // Caves can be sampled with any `Rng`, and combined with rooms.
+/// let cave = thread_rng().sample(generator);
+/// let values: Box<[(Inclusion, Box<dyn PlacedShape>); 2]> = Box::new([
+///     (Inclusion::Include, Box::new(cave)),
+///     (Inclusion::Include, Box::new(Oval::new(Position::new(-3, -3), Size::new(7, 7)))),
+/// ]);
+/// let level = PlacedShapeSlice::new(values);
+/// assert!(level.contains_position(Position::new(0, 0)) == Containment::Contains);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CaveGenerator {
+    area: Area,
+    fill_probability: f64,
+    birth: [bool; 9],
+    survival: [bool; 9],
+    passes: Count,
+    region_retention: RegionRetention,
+}
+
+impl CaveGenerator {
+    /// Creates a new `CaveGenerator` for the `Area`.
+    ///
+    /// Birth and survival counts greater than eight are ignored.
+    pub fn new(
+        area: Area,
+        fill_probability: f64,
+        birth: &[Count],
+        survival: &[Count],
+        passes: Count,
+        region_retention: RegionRetention,
+    ) -> Self {
+        let to_rule = |counts: &[Count]| {
+            let mut rule = [false; 9];
+            for count in counts.iter().filter(|count| **count <= 8) {
+                rule[*count] = true;
+            }
+            rule
+        };
+
+        Self {
+            area,
+            fill_probability,
+            birth: to_rule(birth),
+            survival: to_rule(survival),
+            passes,
+            region_retention,
+        }
+    }
+
+    /// Returns the `Area` caves are generated in.
+    pub fn area(&self) -> Area {
+        self.area
+    }
+
+    /// Returns the numbers of open neighbours which open a closed tile.
+    pub fn birth(&self) -> Vec<Count> {
+        rule_counts(&self.birth)
+    }
+
+    /// Returns the probability of each tile starting open.
+    pub fn fill_probability(&self) -> f64 {
+        self.fill_probability
+    }
+
+    /// Generates a cave from the seed; the same seed always generates the same cave, on every platform.
+    pub fn generate_from_seed(&self, seed: u64) -> BitmaskShape {
+        self.sample(&mut ChaCha8Rng::seed_from_u64(seed))
+    }
+
+    /// Returns the number of smoothing passes.
+    pub fn passes(&self) -> Count {
+        self.passes
+    }
+
+    /// Returns which regions of the cave are kept.
+    pub fn region_retention(&self) -> RegionRetention {
+        self.region_retention
+    }
+
+    /// Returns the numbers of open neighbours which keep an open tile open.
+    pub fn survival(&self) -> Vec<Count> {
+        rule_counts(&self.survival)
+    }
+}
+
+fn rule_counts(rule: &[bool; 9]) -> Vec<Count> {
+    (0..rule.len()).filter(|count| rule[*count]).collect()
+}
+
+/// Returns the separate regions of the tiles which are set in a grid, in the order of their top-most, then left-most, tile.
+///
+/// The grid is stored row by row, and tiles are in the same region if they are adjacent under the `Connectivity`.
+pub(crate) fn connected_regions(
+    width: usize,
+    height: usize,
+    is_set: &[bool],
+    connectivity: Connectivity,
+) -> Vec<Vec<Position>> {
+    let mut is_visited = vec![false; is_set.len()];
+    let mut output = Vec::new();
+    let mut stack = Vec::new();
+    for index in 0..is_set.len() {
+        if !is_set[index] || is_visited[index] {
+            continue;
+        }
+
+        let mut region = Vec::new();
+        is_visited[index] = true;
+        stack.push(Position::new(
+            (index % width) as Coord,
+            (index / width) as Coord,
+        ));
+        while let Some(position) = stack.pop() {
+            region.push(position);
+            for offset in connectivity.offsets().iter() {
+                let next = position + *offset;
+                if next.x() < 0
+                    || next.y() < 0
+                    || next.x() as usize >= width
+                    || next.y() as usize >= height
+                {
+                    continue;
+                }
+
+                let next_index = (next.y() as usize * width) + next.x() as usize;
+                if is_set[next_index] && !is_visited[next_index] {
+                    is_visited[next_index] = true;
+                    stack.push(next);
+                }
+            }
+        }
+        output.push(region);
+    }
+
+    output
+}
+
+impl Distribution<BitmaskShape> for CaveGenerator {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> BitmaskShape {
+        let width = self.area.width() as usize;
+        let height = self.area.height() as usize;
+        let mut is_open: Vec<bool> = (0..width * height)
+            .map(|_| rng.gen::<f64>() < self.fill_probability)
+            .collect();
+
+        let mut next = is_open.clone();
+        for _ in 0..self.passes {
+            for y in 0..height {
+                for x in 0..width {
+                    let open_neighbours = Connectivity::Eight
+                        .offsets()
+                        .iter()
+                        .filter(|offset| {
+                            let x = x as Coord + offset.x();
+                            let y = y as Coord + offset.y();
+                            x >= 0
+                                && y >= 0
+                                && (x as usize) < width
+                                && (y as usize) < height
+                                && is_open[(y as usize * width) + x as usize]
+                        })
+                        .count();
+
+                    let index = (y * width) + x;
+                    next[index] = if is_open[index] {
+                        self.survival[open_neighbours]
+                    } else {
+                        self.birth[open_neighbours]
+                    };
+                }
+            }
+            std::mem::swap(&mut is_open, &mut next);
+        }
+
+        let regions = connected_regions(width, height, &is_open, Connectivity::Four);
+        let kept: Vec<&Vec<Position>> = match self.region_retention {
+            // Searching in reverse keeps the first of the largest regions.
+            RegionRetention::Largest => regions
+                .iter()
+                .rev()
+                .max_by_key(|region| region.len())
+                .into_iter()
+                .collect(),
+            RegionRetention::All => regions.iter().collect(),
+        };
+
+        let mut bitmask = BitmaskShape::new(self.area);
+        for position in kept.into_iter().flatten() {
+            bitmask.set_local_containment(*position, Containment::Intersects);
+        }
+        bitmask.recalculate_containment();
+        bitmask
+    }
+}
+
+impl ProvidesPlacedShape for CaveGenerator {
+    fn provide_placed_shape(&self) -> Box<dyn PlacedShape> {
+        Box::new(self.sample(&mut thread_rng()))
+    }
+}
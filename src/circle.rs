@@ -0,0 +1,196 @@
+// External includes.
+
+// Standard includes.
+
+// Internal includes.
+use super::containment::containment_from_neighbours;
+use super::intersects_shape::rasterized_intersects_shape;
+use super::{
+    Area, Containment, ContainsLocalPosition, ContainsPosition, Coord, CountsTiles, HasArea,
+    HasPosition, HasSize, IntersectsLocalPosition, IntersectsPlacedShape, IntersectsPosition,
+    IntersectsShape, IsArea, IsPosition, IsSize, Length, Placed, PlacedObject, PlacedShape,
+    Position, ProvidesArea, ProvidesPlacedShape, ProvidesSize, RadiusRounding, Shape, Size,
+};
+
+/// Defines a circle by the [`Position`](struct.Position.html) of its center tile and a radius, measured in tiles.
+///
+/// A `Circle` always has a center tile, and is `radius * 2 + 1` tiles across. Which tiles are within the radius is decided by a [`RadiusRounding`](enum.RadiusRounding.html). A tile is contained only if all eight of its neighbours intersect.
+/// ```
+/// # use dungen_minion_geometry::*;
+/// let circle = Circle::new(Position::new(10, 10), 3, RadiusRounding::Round);
+/// assert!(*circle.area() == Area::new(Position::new(7, 7), Size::new(7, 7)));
+/// assert!(circle.center() == Position::new(10, 10));
+/// assert!(
+///     circle.render_ascii(&AsciiPalette::default())
+///         == concat!(
+///             "  ###  \n",
+///             " ##.## \n",
+///             "##...##\n",
+///             "#.....#\n",
+///             "##...##\n",
+///             " ##.## \n",
+///             "  ###  \n",
+///         )
+/// );
+///
+/// // A radius of zero is a single tile.
+/// let circle = Circle::new(Position::new(-2, 5), 0, RadiusRounding::Inner);
+/// assert!(circle.tiles().collect::<Vec<_>>() == vec![(Position::new(-2, 5), Containment::Intersects)]);
+///
+/// // Every circle is symmetric, and each rounding includes every tile of the tighter roundings.
+/// for radius in 0..24 {
+///     let inner = Circle::new(Position::new(0, 0), radius, RadiusRounding::Inner);
+///     let round = Circle::new(Position::new(0, 0), radius, RadiusRounding::Round);
+///     let outer = Circle::new(Position::new(0, 0), radius, RadiusRounding::Outer);
+///     for (position, containment) in round.tiles() {
+///         assert!(round.contains_position(Position::new(-position.x(), position.y())) == containment);
+///         assert!(round.contains_position(Position::new(position.y(), position.x())) == containment);
+///     }
+///     assert!(inner.tiles().all(|(position, _)| round.intersects_position(position)));
+///     assert!(round.tiles().all(|(position, _)| outer.intersects_position(position)));
+///     // The tiles straight out from the center are always within the radius.
+///     let radius = radius as Coord;
+///     assert!(inner.intersects_position(Position::new(radius, 0)));
+///     assert!(!outer.intersects_position(Position::new(radius + 1, 0)));
+/// }
+///
+/// // Moving a circle moves its center.
+/// let mut circle = Circle::new(Position::new(0, 0), 2, RadiusRounding::Round);
+/// *circle.position_mut() = Position::new(0, 0);
+/// assert!(circle.center() == Position::new(2, 2));
+/// assert!(circle.contains_position(Position::new(2, 2)) == Containment::Contains);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Circle {
+    area: Area,
+    radius: Length,
+    rounding: RadiusRounding,
+}
+
+impl Circle {
+    /// Creates a new `Circle` around the given center tile, including the tiles within the radius as decided by the `RadiusRounding`.
+    pub fn new(center: Position, radius: Length, rounding: RadiusRounding) -> Self {
+        let diameter = (radius * 2) + 1;
+        Self {
+            area: Area::new(
+                center - Position::new(radius as Coord, radius as Coord),
+                Size::new(diameter, diameter),
+            ),
+            radius,
+            rounding,
+        }
+    }
+
+    /// Returns the `Position` of the center tile of the circle.
+    pub fn center(&self) -> Position {
+        *self.position() + Position::new(self.radius as Coord, self.radius as Coord)
+    }
+
+    /// Returns the radius of the circle, in tiles.
+    pub fn radius(&self) -> Length {
+        self.radius
+    }
+
+    /// Returns the `RadiusRounding` which decides which tiles are within the radius.
+    pub fn rounding(&self) -> RadiusRounding {
+        self.rounding
+    }
+}
+
+impl ContainsLocalPosition for Circle {
+    fn contains_local_position(&self, position: Position) -> Containment {
+        containment_from_neighbours(position, |position| {
+            self.intersects_local_position(position)
+        })
+    }
+}
+
+impl ContainsPosition for Circle {}
+
+impl CountsTiles for Circle {}
+
+impl HasArea for Circle {
+    fn area(&self) -> &Area {
+        &self.area
+    }
+
+    fn area_mut(&mut self) -> &mut Area {
+        &mut self.area
+    }
+}
+
+impl HasPosition for Circle {
+    fn position(&self) -> &Position {
+        self.area.position()
+    }
+
+    fn position_mut(&mut self) -> &mut Position {
+        self.area.position_mut()
+    }
+}
+
+impl HasSize for Circle {
+    fn size(&self) -> &Size {
+        self.area.size()
+    }
+
+    fn size_mut(&mut self) -> &mut Size {
+        self.area.size_mut()
+    }
+}
+
+impl IntersectsLocalPosition for Circle {
+    fn intersects_local_position(&self, position: Position) -> bool {
+        let radius = self.radius as Coord;
+        let x = (position.x() - radius).unsigned_abs();
+        let y = (position.y() - radius).unsigned_abs();
+        x <= self.radius && y <= self.radius && self.rounding.is_within(self.radius, x, y)
+    }
+}
+
+impl IntersectsPlacedShape for Circle {}
+
+impl IntersectsPosition for Circle {}
+
+impl IntersectsShape for Circle {
+    fn intersects_shape(&self, other_offset: &Position, other_shape: &dyn Shape) -> bool {
+        rasterized_intersects_shape(
+            self.area(),
+            |position| self.intersects_position(position),
+            other_offset,
+            other_shape,
+        )
+    }
+}
+
+impl IsArea for Circle {}
+
+impl IsSize for Circle {}
+
+impl Placed for Circle {}
+
+impl PlacedObject for Circle {}
+
+impl ProvidesArea for Circle {
+    fn provide_area(&self) -> Area {
+        *self.area()
+    }
+}
+
+impl ProvidesPlacedShape for Circle {
+    fn provide_placed_shape(&self) -> Box<dyn PlacedShape> {
+        Box::new(*self)
+    }
+}
+
+impl ProvidesSize for Circle {
+    fn provide_size(&self) -> Size {
+        *self.area.size()
+    }
+}
+
+impl Shape for Circle {
+    fn box_shape_clone(&self) -> Box<dyn Shape> {
+        Box::new(*self)
+    }
+}
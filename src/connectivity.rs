@@ -0,0 +1,50 @@
+// External includes.
+
+// Standard includes.
+
+// Internal includes.
+use super::Position;
+
+/// Defines which neighbouring tiles are considered adjacent to a tile.
+#[derive(Copy, Clone, Debug, Display, Eq, Hash, PartialEq)]
+pub enum Connectivity {
+    /// Tiles are adjacent if they share a side.
+    Four,
+    /// Tiles are adjacent if they share a side or a corner.
+    Eight,
+}
+
+impl Connectivity {
+    /// Returns the offsets of the adjacent tiles, in counter-clockwise order starting with east.
+    ///
+    /// ```
+    /// # use dungen_minion_geometry::*;
+    /// assert!(Connectivity::Four.offsets().len() == 4);
+    /// assert!(Connectivity::Eight.offsets().len() == 8);
+    /// assert!(Connectivity::Four.offsets()[0] == Position::EAST);
+    /// assert!(Connectivity::Four.offsets()[1] == Position::NORTH);
+    /// assert!(Connectivity::Eight.offsets()[1] == Position::NORTH + Position::EAST);
+    /// ```
+    pub fn offsets(self) -> &'static [Position] {
+        const FOUR: [Position; 4] = [
+            Position::EAST,
+            Position::NORTH,
+            Position::WEST,
+            Position::SOUTH,
+        ];
+        const EIGHT: [Position; 8] = [
+            Position::EAST,
+            Position::new(1, -1),
+            Position::NORTH,
+            Position::new(-1, -1),
+            Position::WEST,
+            Position::new(-1, 1),
+            Position::SOUTH,
+            Position::new(1, 1),
+        ];
+        match self {
+            Connectivity::Four => &FOUR,
+            Connectivity::Eight => &EIGHT,
+        }
+    }
+}
@@ -4,6 +4,7 @@
 use std::cmp::{Ordering, PartialOrd};
 
 // Internal includes.
+use super::Position;
 
 /// An enum that specifies the intersection of two shapes to a more precise level of detail.
 #[derive(Copy, Clone, Debug, Display, Eq, PartialEq)]
@@ -45,6 +46,31 @@ impl Ord for Containment {
 
 impl PartialOrd for Containment {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        i8::from(*self).partial_cmp(&i8::from(*other))
+        Some(self.cmp(other))
     }
 }
+
+/// Classifies an intersecting tile as `Containment::Contains` if all eight of its neighbours also intersect, and as `Containment::Intersects` otherwise.
+///
+/// This is the convention used by [`Area`](struct.Area.html): a tile is only contained if nothing outside of the shape is adjacent to it.
+pub(crate) fn containment_from_neighbours<TIntersectsPosition>(
+    position: Position,
+    intersects_position: TIntersectsPosition,
+) -> Containment
+where
+    TIntersectsPosition: Fn(Position) -> bool,
+{
+    if !intersects_position(position) {
+        return Containment::Disjoint;
+    }
+
+    for y in -1..=1 {
+        for x in -1..=1 {
+            if (x != 0 || y != 0) && !intersects_position(position + Position::new(x, y)) {
+                return Containment::Intersects;
+            }
+        }
+    }
+
+    Containment::Contains
+}
@@ -0,0 +1,19 @@
+// External includes.
+
+// Standard includes.
+
+// Internal includes.
+use super::Length;
+
+/// Defines the shape of a corner of a [`CorneredArea`](struct.CorneredArea.html).
+#[derive(Copy, Clone, Debug, Display, Eq, Hash, PartialEq)]
+pub enum CornerStyle {
+    /// A square corner, as an [`Area`](struct.Area.html) has.
+    Square,
+    /// A corner rounded as a quarter of an [`Oval`](struct.Oval.html), with the given radius in tiles.
+    #[display(fmt = "Rounded({})", _0)]
+    Rounded(Length),
+    /// A corner cut off diagonally, removing the given number of tiles from each side that meets at the corner.
+    #[display(fmt = "Chamfered({})", _0)]
+    Chamfered(Length),
+}
@@ -0,0 +1,273 @@
+// External includes.
+
+// Standard includes.
+
+// Internal includes.
+use super::containment::containment_from_neighbours;
+use super::intersects_shape::rasterized_intersects_shape;
+use super::{
+    Area, Containment, ContainsLocalPosition, ContainsPosition, Coord, CornerStyle, CountsTiles,
+    HasArea, HasHeight, HasPosition, HasSize, HasWidth, IntersectsLocalPosition,
+    IntersectsPlacedShape, IntersectsPosition, IntersectsShape, IsArea, IsPosition, IsSize, Length,
+    Oval, Placed, PlacedObject, PlacedShape, Position, ProvidesArea, ProvidesPlacedShape,
+    ProvidesSize, Shape, Size,
+};
+
+/// Defines an [`Area`](struct.Area.html) whose corners may be rounded or cut off, each as set by a [`CornerStyle`](enum.CornerStyle.html).
+///
+/// A rounded corner is a quarter of an [`Oval`](struct.Oval.html) twice as wide as its radius, and a chamfered corner is cut off along a diagonal. A corner never extends past the middle of the sides it meets, so larger radii and cut lengths are clamped to half the width or height, whichever is smaller. As with `Oval`, a tile is contained only if all eight of its neighbours intersect.
+/// ```
+/// # use dungen_minion_geometry::*;
+/// let area = Area::new(Position::new(0, 0), Size::new(9, 6));
+/// let rounded = CorneredArea::new(area, CornerStyle::Rounded(2));
+/// assert!(
+///     rounded.render_ascii(&AsciiPalette::default())
+///         == concat!(
+///             " ####### \n",
+///             "##.....##\n",
+///             "#.......#\n",
+///             "#.......#\n",
+///             "##.....##\n",
+///             " ####### \n",
+///         )
+/// );
+///
+/// let chamfered = CorneredArea::new(area, CornerStyle::Chamfered(3));
+/// assert!(
+///     chamfered.render_ascii(&AsciiPalette::default())
+///         == concat!(
+///             "   ###   \n",
+///             "  ##.##  \n",
+///             " ##...## \n",
+///             " ##...## \n",
+///             "  ##.##  \n",
+///             "   ###   \n",
+///         )
+/// );
+///
+/// // Each corner can be styled on its own.
+/// let mixed = CorneredArea::with_corners(
+///     area,
+///     CornerStyle::Square,
+///     CornerStyle::Rounded(3),
+///     CornerStyle::Chamfered(1),
+///     CornerStyle::Square,
+/// );
+/// assert!(mixed.top_left() == CornerStyle::Square);
+/// assert!(mixed.intersects_position(Position::new(0, 0)));
+/// assert!(!mixed.intersects_position(Position::new(8, 0)));
+/// assert!(!mixed.intersects_position(Position::new(8, 5)));
+/// assert!(mixed.intersects_position(Position::new(7, 5)));
+/// assert!(mixed.intersects_position(Position::new(0, 5)));
+///
+/// // A `CorneredArea` has the same bounds as its `Area`.
+/// assert!(rounded.left() == area.left() && rounded.right() == area.right());
+/// assert!(rounded.top() == area.top() && rounded.bottom() == area.bottom());
+/// assert!(rounded.provide_area() == area);
+///
+/// // Square corners are an `Area`, and an even square with rounded corners of half its width is an `Oval`.
+/// for width in 0..16 {
+///     for height in 0..16 {
+///         let area = Area::new(Position::new(0, 0), Size::new(width, height));
+///         let square = CorneredArea::new(area, CornerStyle::Square);
+///         for (position, containment) in area.tiles() {
+///             assert!(square.contains_position(position) == containment);
+///         }
+///     }
+///     let size = Size::new(width * 2, width * 2);
+///     let oval = Oval::new(Position::new(0, 0), size);
+///     let rounded = CorneredArea::new(Area::new(Position::new(0, 0), size), CornerStyle::Rounded(width));
+///     for (position, containment) in rounded.tiles() {
+///         assert!(oval.contains_position(position) == containment);
+///     }
+///     assert!(rounded.tiles().count() == oval.tiles().count());
+/// }
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct CorneredArea {
+    area: Area,
+    top_left: CornerStyle,
+    top_right: CornerStyle,
+    bottom_right: CornerStyle,
+    bottom_left: CornerStyle,
+}
+
+impl CorneredArea {
+    /// Creates a new `CorneredArea` with every corner styled the same.
+    pub fn new(area: Area, corners: CornerStyle) -> Self {
+        Self::with_corners(area, corners, corners, corners, corners)
+    }
+
+    /// Creates a new `CorneredArea` with each corner styled on its own, in clockwise order from the top-left corner.
+    pub fn with_corners(
+        area: Area,
+        top_left: CornerStyle,
+        top_right: CornerStyle,
+        bottom_right: CornerStyle,
+        bottom_left: CornerStyle,
+    ) -> Self {
+        Self {
+            area,
+            top_left,
+            top_right,
+            bottom_right,
+            bottom_left,
+        }
+    }
+
+    /// Returns the style of the bottom-left corner.
+    pub fn bottom_left(&self) -> CornerStyle {
+        self.bottom_left
+    }
+
+    /// Returns the style of the bottom-right corner.
+    pub fn bottom_right(&self) -> CornerStyle {
+        self.bottom_right
+    }
+
+    /// Returns the style of the top-left corner.
+    pub fn top_left(&self) -> CornerStyle {
+        self.top_left
+    }
+
+    /// Returns the style of the top-right corner.
+    pub fn top_right(&self) -> CornerStyle {
+        self.top_right
+    }
+}
+
+impl ContainsLocalPosition for CorneredArea {
+    fn contains_local_position(&self, position: Position) -> Containment {
+        containment_from_neighbours(position, |position| {
+            self.intersects_local_position(position)
+        })
+    }
+}
+
+impl ContainsPosition for CorneredArea {}
+
+impl CountsTiles for CorneredArea {}
+
+impl HasArea for CorneredArea {
+    fn area(&self) -> &Area {
+        &self.area
+    }
+
+    fn area_mut(&mut self) -> &mut Area {
+        &mut self.area
+    }
+}
+
+impl HasPosition for CorneredArea {
+    fn position(&self) -> &Position {
+        self.area.position()
+    }
+
+    fn position_mut(&mut self) -> &mut Position {
+        self.area.position_mut()
+    }
+}
+
+impl HasSize for CorneredArea {
+    fn size(&self) -> &Size {
+        self.area.size()
+    }
+
+    fn size_mut(&mut self) -> &mut Size {
+        self.area.size_mut()
+    }
+}
+
+impl IntersectsLocalPosition for CorneredArea {
+    fn intersects_local_position(&self, position: Position) -> bool {
+        let width = self.width();
+        let height = self.height();
+        if position.x() < 0
+            || position.y() < 0
+            || position.x() as Length >= width
+            || position.y() as Length >= height
+        {
+            return false;
+        }
+
+        // Measure from the nearest corner, as though it were the top-left corner.
+        let x = position.x() as Length;
+        let y = position.y() as Length;
+        let (is_left, x) = if x * 2 < width {
+            (true, x)
+        } else {
+            (false, width - 1 - x)
+        };
+        let (is_top, y) = if y * 2 < height {
+            (true, y)
+        } else {
+            (false, height - 1 - y)
+        };
+        let corner = match (is_left, is_top) {
+            (true, true) => self.top_left,
+            (false, true) => self.top_right,
+            (false, false) => self.bottom_right,
+            (true, false) => self.bottom_left,
+        };
+
+        let limit = width.min(height) / 2;
+        match corner {
+            CornerStyle::Square => true,
+            CornerStyle::Rounded(radius) => {
+                let radius = radius.min(limit);
+                x >= radius
+                    || y >= radius
+                    || Oval::new(Position::zero(), Size::new(radius * 2, radius * 2))
+                        .intersects_local_position(Position::new(x as Coord, y as Coord))
+            }
+            CornerStyle::Chamfered(length) => x + y >= length.min(limit),
+        }
+    }
+}
+
+impl IntersectsPlacedShape for CorneredArea {}
+
+impl IntersectsPosition for CorneredArea {}
+
+impl IntersectsShape for CorneredArea {
+    fn intersects_shape(&self, other_offset: &Position, other_shape: &dyn Shape) -> bool {
+        rasterized_intersects_shape(
+            self.area(),
+            |position| self.intersects_position(position),
+            other_offset,
+            other_shape,
+        )
+    }
+}
+
+impl IsArea for CorneredArea {}
+
+impl IsSize for CorneredArea {}
+
+impl Placed for CorneredArea {}
+
+impl PlacedObject for CorneredArea {}
+
+impl ProvidesArea for CorneredArea {
+    fn provide_area(&self) -> Area {
+        *self.area()
+    }
+}
+
+impl ProvidesPlacedShape for CorneredArea {
+    fn provide_placed_shape(&self) -> Box<dyn PlacedShape> {
+        Box::new(*self)
+    }
+}
+
+impl ProvidesSize for CorneredArea {
+    fn provide_size(&self) -> Size {
+        *self.area.size()
+    }
+}
+
+impl Shape for CorneredArea {
+    fn box_shape_clone(&self) -> Box<dyn Shape> {
+        Box::new(*self)
+    }
+}
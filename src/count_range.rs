@@ -46,10 +46,7 @@ impl CountRange {
 }
 
 impl Distribution<Count> for CountRange {
-    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Count
-    where
-        R: Rng,
-    {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Count {
         rng.gen_range(self.min_count, self.max_count + 1)
     }
 }
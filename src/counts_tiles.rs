@@ -0,0 +1,69 @@
+// External includes.
+
+// Standard includes.
+
+// Internal includes.
+use super::{Containment, IteratesLocalTiles, Shape, TileCount};
+
+/// Counts the tiles a [`Shape`](trait.Shape.html) covers, split into interior and edge tiles.
+///
+/// The default implementation visits every tile within the shape's [`Size`](struct.Size.html); shapes which can count their tiles faster override it. Shapes which are unbounded, such as an [`InvertPlacedShape`](struct.InvertPlacedShape.html), are counted within their `Size`.
+/// ```
+/// # use dungen_minion_geometry::*;
+/// let room = Area::new(Position::new(0, 0), Size::new(7, 5));
+/// assert!(room.count_tiles() == TileCount::new(15, 20));
+///
+/// // Rooms below a minimum floor area can be rejected without visiting any tiles.
+/// let minimum_floor = 20;
+/// assert!(room.count_tiles().interior() < minimum_floor);
+///
+/// // Every shape agrees with counting its tiles one by one.
+/// fn count_by_tile(shape: &dyn PlacedShape) -> TileCount {
+///     let mut count = TileCount::default();
+///     for (_, containment) in shape.local_tiles() {
+///         match containment {
+///             Containment::Contains => count += TileCount::new(1, 0),
+///             _ => count += TileCount::new(0, 1),
+///         }
+///     }
+///     count
+/// }
+///
+/// for height in 0..24 {
+///     for width in 0..24 {
+///         let size = Size::new(width, height);
+///         let area = Area::new(Position::new(-3, 2), size);
+///         assert!(area.count_tiles() == count_by_tile(&area));
+///         let oval = Oval::new(Position::new(-3, 2), size);
+///         assert!(oval.count_tiles() == count_by_tile(&oval));
+///         let bitmask = BitmaskShape::from_placed_shape(&oval);
+///         assert!(bitmask.count_tiles() == count_by_tile(&oval));
+///     }
+/// }
+///
+/// // Composite shapes are counted too.
+/// let room = Area::new(Position::new(0, 0), Size::new(9, 9));
+/// let pillar = Area::new(Position::new(3, 3), Size::new(3, 3));
+/// // Only the interior of the pillar is cut out; the tiles around it become walls.
+/// let room_with_pillar =
+///     BooleanPlacedShape::new(BooleanOperation::Difference, Box::new(room), Box::new(pillar));
+/// assert!(room_with_pillar.count_tiles() == TileCount::new(40, 40));
+/// let boxed: Box<dyn PlacedShape> = Box::new(room_with_pillar);
+/// assert!(boxed.count_tiles().total() == 80);
+/// ```
+pub trait CountsTiles: Shape {
+    /// Returns the number of interior and edge tiles this instance covers.
+    fn count_tiles(&self) -> TileCount {
+        let mut interior = 0;
+        let mut edge = 0;
+        for (_, containment) in self.local_tiles() {
+            if containment == Containment::Contains {
+                interior += 1;
+            } else {
+                edge += 1;
+            }
+        }
+
+        TileCount::new(interior, edge)
+    }
+}
@@ -0,0 +1,227 @@
+// External includes.
+
+// Standard includes.
+
+// Internal includes.
+use super::containment::containment_from_neighbours;
+use super::intersects_shape::rasterized_intersects_shape;
+use super::{
+    Area, Containment, ContainsLocalPosition, ContainsPosition, Coord, CountsTiles, HasArea,
+    HasHeight, HasPosition, HasSize, HasWidth, IntersectsLocalPosition, IntersectsPlacedShape,
+    IntersectsPosition, IntersectsShape, IsArea, IsPosition, IsSize, Placed, PlacedObject,
+    PlacedShape, Position, ProvidesArea, ProvidesPlacedShape, ProvidesSize, Shape, Size,
+};
+
+/// Defines a cross; a vertical arm and a horizontal arm which span an [`Area`](struct.Area.html), crossing each other.
+///
+/// The width of the vertical arm and the height of the horizontal arm are given by an arm thickness [`Size`](struct.Size.html). The arms cross in the middle of the `Area`, or moved from the middle by an offset; arms are clamped so that they stay within the `Area`. A tile is contained only if all eight of its neighbours intersect, so the corners where the arms meet are walls.
+/// ```
+/// # use dungen_minion_geometry::*;
+/// let area = Area::new(Position::new(0, 0), Size::new(9, 7));
+/// let cross = Cross::new(area, Size::new(3, 3));
+/// assert!(cross.crossing() == Area::new(Position::new(3, 2), Size::new(3, 3)));
+/// assert!(
+///     cross.render_ascii(&AsciiPalette::default())
+///         == concat!(
+///             "   ###   \n",
+///             "   #.#   \n",
+///             "####.####\n",
+///             "#.......#\n",
+///             "####.####\n",
+///             "   #.#   \n",
+///             "   ###   \n",
+///         )
+/// );
+///
+/// // The corners where the arms meet are walls, and the tiles diagonally inside them are not.
+/// for corner in [Position::new(3, 2), Position::new(5, 2), Position::new(3, 4), Position::new(5, 4)].iter() {
+///     assert!(cross.contains_position(*corner) == Containment::Intersects);
+/// }
+/// assert!(cross.contains_position(Position::new(4, 3)) == Containment::Contains);
+///
+/// // The arms can cross away from the middle, and may be of different thicknesses.
+/// let offset_cross = Cross::with_offset(area, Size::new(1, 2), Position::new(-2, 2));
+/// assert!(offset_cross.crossing() == Area::new(Position::new(2, 4), Size::new(1, 2)));
+/// assert!(
+///     offset_cross.render_ascii(&AsciiPalette::default())
+///         == concat!(
+///             "  #      \n",
+///             "  #      \n",
+///             "  #      \n",
+///             "  #      \n",
+///             "#########\n",
+///             "#########\n",
+///             "  #      \n",
+///         )
+/// );
+///
+/// // Offsets are clamped, so that the arms stay within the area.
+/// let clamped = Cross::with_offset(area, Size::new(3, 3), Position::new(100, -100));
+/// assert!(clamped.crossing() == Area::new(Position::new(6, 0), Size::new(3, 3)));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Cross {
+    area: Area,
+    arm_thickness: Size,
+    offset: Position,
+}
+
+impl Cross {
+    /// Creates a new `Cross` spanning the `Area`, with arms of the given thickness crossing in its middle.
+    pub fn new(area: Area, arm_thickness: Size) -> Self {
+        Self::with_offset(area, arm_thickness, Position::zero())
+    }
+
+    /// Creates a new `Cross` spanning the `Area`, with arms of the given thickness crossing at the given offset from its middle.
+    pub fn with_offset(area: Area, arm_thickness: Size, offset: Position) -> Self {
+        Self {
+            area,
+            arm_thickness,
+            offset,
+        }
+    }
+
+    /// Returns the width of the vertical arm, and the height of the horizontal arm.
+    pub fn arm_thickness(&self) -> Size {
+        self.arm_thickness
+    }
+
+    /// Returns the `Area` where the arms cross.
+    pub fn crossing(&self) -> Area {
+        let size = self.arm_size();
+        Area::new(*self.position() + self.local_crossing_position(), size)
+    }
+
+    /// Returns the offset of the arms from the middle of the `Area`, before clamping.
+    pub fn offset(&self) -> Position {
+        self.offset
+    }
+
+    fn arm_size(&self) -> Size {
+        Size::new(
+            self.arm_thickness.width().min(self.width()),
+            self.arm_thickness.height().min(self.height()),
+        )
+    }
+
+    fn local_crossing_position(&self) -> Position {
+        let size = self.arm_size();
+        let free_width = (self.width() - size.width()) as Coord;
+        let free_height = (self.height() - size.height()) as Coord;
+        Position::new(
+            ((free_width / 2) + self.offset.x()).max(0).min(free_width),
+            ((free_height / 2) + self.offset.y())
+                .max(0)
+                .min(free_height),
+        )
+    }
+}
+
+impl ContainsLocalPosition for Cross {
+    fn contains_local_position(&self, position: Position) -> Containment {
+        containment_from_neighbours(position, |position| {
+            self.intersects_local_position(position)
+        })
+    }
+}
+
+impl ContainsPosition for Cross {}
+
+impl CountsTiles for Cross {}
+
+impl HasArea for Cross {
+    fn area(&self) -> &Area {
+        &self.area
+    }
+
+    fn area_mut(&mut self) -> &mut Area {
+        &mut self.area
+    }
+}
+
+impl HasPosition for Cross {
+    fn position(&self) -> &Position {
+        self.area.position()
+    }
+
+    fn position_mut(&mut self) -> &mut Position {
+        self.area.position_mut()
+    }
+}
+
+impl HasSize for Cross {
+    fn size(&self) -> &Size {
+        self.area.size()
+    }
+
+    fn size_mut(&mut self) -> &mut Size {
+        self.area.size_mut()
+    }
+}
+
+impl IntersectsLocalPosition for Cross {
+    fn intersects_local_position(&self, position: Position) -> bool {
+        if position.x() < 0
+            || position.y() < 0
+            || position.x() >= self.width() as Coord
+            || position.y() >= self.height() as Coord
+        {
+            return false;
+        }
+
+        let size = self.arm_size();
+        let crossing = self.local_crossing_position();
+        let in_vertical_arm =
+            position.x() >= crossing.x() && position.x() < crossing.x() + size.width() as Coord;
+        let in_horizontal_arm =
+            position.y() >= crossing.y() && position.y() < crossing.y() + size.height() as Coord;
+        in_vertical_arm || in_horizontal_arm
+    }
+}
+
+impl IntersectsPlacedShape for Cross {}
+
+impl IntersectsPosition for Cross {}
+
+impl IntersectsShape for Cross {
+    fn intersects_shape(&self, other_offset: &Position, other_shape: &dyn Shape) -> bool {
+        rasterized_intersects_shape(
+            self.area(),
+            |position| self.intersects_position(position),
+            other_offset,
+            other_shape,
+        )
+    }
+}
+
+impl IsArea for Cross {}
+
+impl IsSize for Cross {}
+
+impl Placed for Cross {}
+
+impl PlacedObject for Cross {}
+
+impl ProvidesArea for Cross {
+    fn provide_area(&self) -> Area {
+        *self.area()
+    }
+}
+
+impl ProvidesPlacedShape for Cross {
+    fn provide_placed_shape(&self) -> Box<dyn PlacedShape> {
+        Box::new(*self)
+    }
+}
+
+impl ProvidesSize for Cross {
+    fn provide_size(&self) -> Size {
+        *self.area.size()
+    }
+}
+
+impl Shape for Cross {
+    fn box_shape_clone(&self) -> Box<dyn Shape> {
+        Box::new(*self)
+    }
+}
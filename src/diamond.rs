@@ -0,0 +1,212 @@
+// External includes.
+
+// Standard includes.
+
+// Internal includes.
+use super::containment::containment_from_neighbours;
+use super::intersects_shape::rasterized_intersects_shape;
+use super::{
+    Area, Containment, ContainsLocalPosition, ContainsPosition, Coord, CountsTiles, HasArea,
+    HasHeight, HasPosition, HasSize, HasWidth, IntersectsLocalPosition, IntersectsPlacedShape,
+    IntersectsPosition, IntersectsShape, IsArea, IsPosition, IsSize, Length, Placed, PlacedObject,
+    PlacedShape, Position, ProvidesArea, ProvidesPlacedShape, ProvidesSize, Shape, Size,
+};
+
+/// Defines a diamond; the tiles within a Manhattan distance of a center tile, optionally stretched to fill a [`Size`](struct.Size.html).
+///
+/// A stretched diamond touches the middle of each side of the rectangle surrounding it. A tile intersects the diamond if its center lies within that diamond, or if it is in the middle row or column of the rectangle, so that the diamond fills its rectangle however thin it is. As with [`Area`](struct.Area.html), a tile is contained only if all eight of its neighbours intersect.
+/// ```
+/// # use dungen_minion_geometry::*;
+/// let diamond = Diamond::new(Position::new(10, 10), 3);
+/// assert!(*diamond.area() == Area::new(Position::new(7, 7), Size::new(7, 7)));
+/// assert!(diamond.center() == Position::new(10, 10));
+/// assert!(
+///     diamond.render_ascii(&AsciiPalette::default())
+///         == concat!(
+///             "   #   \n",
+///             "  ###  \n",
+///             " ##.## \n",
+///             "##...##\n",
+///             " ##.## \n",
+///             "  ###  \n",
+///             "   #   \n",
+///         )
+/// );
+///
+/// // Every tile within the Manhattan distance is part of the diamond.
+/// for y in 7..=13 {
+///     for x in 7..=13 {
+///         let distance = (x - 10i32).abs() + (y - 10i32).abs();
+///         assert!(diamond.intersects_position(Position::new(x, y)) == (distance <= 3));
+///     }
+/// }
+///
+/// // Stretched diamonds fill their rectangle.
+/// let stretched = Diamond::with_size(Position::new(0, 0), Size::new(9, 3));
+/// assert!(
+///     stretched.render_ascii(&AsciiPalette::default())
+///         == concat!(
+///             "   ###   \n",
+///             "####.####\n",
+///             "   ###   \n",
+///         )
+/// );
+///
+/// // Diamonds can be inverted, to carve out everything but a diamond.
+/// let inverted = InvertPlacedShape::new(diamond);
+/// assert!(inverted.contains_position(Position::new(7, 7)) == Containment::Contains);
+/// assert!(inverted.contains_position(Position::new(10, 7)) == Containment::Intersects);
+/// assert!(inverted.contains_position(Position::new(10, 10)) == Containment::Disjoint);
+///
+/// // Every diamond is symmetric.
+/// for width in 0..16 {
+///     for height in 0..16 {
+///         let diamond = Diamond::with_size(Position::new(0, 0), Size::new(width, height));
+///         for (position, containment) in diamond.tiles() {
+///             let mirrored = Position::new(width as Coord - 1 - position.x(), height as Coord - 1 - position.y());
+///             assert!(diamond.contains_position(mirrored) == containment);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Diamond {
+    area: Area,
+}
+
+impl Diamond {
+    /// Creates a new `Diamond` of the tiles within the given Manhattan distance of the center tile.
+    pub fn new(center: Position, radius: Length) -> Self {
+        let diameter = (radius * 2) + 1;
+        Self::with_size(
+            center - Position::new(radius as Coord, radius as Coord),
+            Size::new(diameter, diameter),
+        )
+    }
+
+    /// Creates a new `Diamond` stretched to fill the rectangle with the given top-left corner and `Size`.
+    pub fn with_size(position: Position, size: Size) -> Self {
+        Self {
+            area: Area::new(position, size),
+        }
+    }
+
+    /// Returns the `Position` of the center tile of the diamond; where the diamond has an even width or height, the center is the tile above or left of the true center.
+    pub fn center(&self) -> Position {
+        *self.position()
+            + Position::new(
+                (self.width() as Coord - 1).max(0) / 2,
+                (self.height() as Coord - 1).max(0) / 2,
+            )
+    }
+}
+
+impl ContainsLocalPosition for Diamond {
+    fn contains_local_position(&self, position: Position) -> Containment {
+        containment_from_neighbours(position, |position| {
+            self.intersects_local_position(position)
+        })
+    }
+}
+
+impl ContainsPosition for Diamond {}
+
+impl CountsTiles for Diamond {}
+
+impl HasArea for Diamond {
+    fn area(&self) -> &Area {
+        &self.area
+    }
+
+    fn area_mut(&mut self) -> &mut Area {
+        &mut self.area
+    }
+}
+
+impl HasPosition for Diamond {
+    fn position(&self) -> &Position {
+        self.area.position()
+    }
+
+    fn position_mut(&mut self) -> &mut Position {
+        self.area.position_mut()
+    }
+}
+
+impl HasSize for Diamond {
+    fn size(&self) -> &Size {
+        self.area.size()
+    }
+
+    fn size_mut(&mut self) -> &mut Size {
+        self.area.size_mut()
+    }
+}
+
+impl IntersectsLocalPosition for Diamond {
+    fn intersects_local_position(&self, position: Position) -> bool {
+        let width = self.width() as i64;
+        let height = self.height() as i64;
+        let x = position.x() as i64;
+        let y = position.y() as i64;
+        if x < 0 || y < 0 || x >= width || y >= height {
+            return false;
+        }
+
+        // Offsets from the center are doubled, so that they are whole numbers for both odd and even sizes.
+        let offset_x = ((2 * x) - (width - 1)).abs();
+        let offset_y = ((2 * y) - (height - 1)).abs();
+
+        // The middle row and column are always kept, so that the diamond fills its bounding rectangle however thin it is.
+        (offset_x <= (width - 1) % 2)
+            || (offset_y <= (height - 1) % 2)
+            || ((offset_x * height) + (offset_y * width) <= width * height)
+    }
+}
+
+impl IntersectsPlacedShape for Diamond {}
+
+impl IntersectsPosition for Diamond {}
+
+impl IntersectsShape for Diamond {
+    fn intersects_shape(&self, other_offset: &Position, other_shape: &dyn Shape) -> bool {
+        rasterized_intersects_shape(
+            self.area(),
+            |position| self.intersects_position(position),
+            other_offset,
+            other_shape,
+        )
+    }
+}
+
+impl IsArea for Diamond {}
+
+impl IsSize for Diamond {}
+
+impl Placed for Diamond {}
+
+impl PlacedObject for Diamond {}
+
+impl ProvidesArea for Diamond {
+    fn provide_area(&self) -> Area {
+        *self.area()
+    }
+}
+
+impl ProvidesPlacedShape for Diamond {
+    fn provide_placed_shape(&self) -> Box<dyn PlacedShape> {
+        Box::new(*self)
+    }
+}
+
+impl ProvidesSize for Diamond {
+    fn provide_size(&self) -> Size {
+        *self.area.size()
+    }
+}
+
+impl Shape for Diamond {
+    fn box_shape_clone(&self) -> Box<dyn Shape> {
+        Box::new(*self)
+    }
+}
@@ -0,0 +1,39 @@
+// External includes.
+
+// Standard includes.
+
+// Internal includes.
+use super::{IsPosition, Position};
+
+/// Defines how the distance between two [`Position`](struct.Position.html)s is measured.
+///
+/// ```
+/// # use dungen_minion_geometry::*;
+/// let from = Position::new(1, 2);
+/// let to = Position::new(4, 6);
+/// assert!(DistanceMetric::Manhattan.distance(from, to) == 7.0);
+/// assert!(DistanceMetric::Chebyshev.distance(from, to) == 4.0);
+/// assert!(DistanceMetric::Euclidean.distance(from, to) == 5.0);
+/// ```
+#[derive(Copy, Clone, Debug, Display, Eq, Hash, PartialEq)]
+pub enum DistanceMetric {
+    /// The number of steps between tiles which share a side.
+    Manhattan,
+    /// The number of steps between tiles which share a side or a corner.
+    Chebyshev,
+    /// The straight-line distance between tile centers.
+    Euclidean,
+}
+
+impl DistanceMetric {
+    /// Returns the distance between the two `Position`s.
+    pub fn distance(self, from: Position, to: Position) -> f64 {
+        let x = (to.x() as f64 - from.x() as f64).abs();
+        let y = (to.y() as f64 - from.y() as f64).abs();
+        match self {
+            DistanceMetric::Manhattan => x + y,
+            DistanceMetric::Chebyshev => x.max(y),
+            DistanceMetric::Euclidean => ((x * x) + (y * y)).sqrt(),
+        }
+    }
+}
@@ -0,0 +1,216 @@
+// External includes.
+use rand::distributions::Distribution;
+use rand::{thread_rng, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+// Standard includes.
+use std::collections::HashSet;
+
+// Internal includes.
+use super::{
+    Area, BitmaskShape, CardinalDirection, Containment, Count, CountRange, HasBottom, HasHeight,
+    HasLeft, HasRight, HasTop, HasWidth, IntersectsPosition, IsPosition, PlacedShape, Position,
+    ProvidesPlacedShape, Size,
+};
+
+/// The number of steps in a row without carving a new tile after which a walk ends.
+const MAX_IDLE_STEPS: usize = 10_000;
+
+/// Generates organic blobs by carving out the tiles of a random walk, as a drunkard stumbling from a starting [`Position`](struct.Position.html).
+///
+/// Each step moves one tile in a random [`CardinalDirection`](enum.CardinalDirection.html), and the walk ends once it has carved a target number of tiles, sampled from a [`CountRange`](struct.CountRange.html). Each direction has a weight of `1.0`; a bias adds its weight to one direction, so that the walk drifts that way.
+///
+/// Steps which would leave the bounding [`Area`](struct.Area.html), if one is given, are not taken; the start is moved inside the bounds, and the target is capped at the number of tiles in the bounds.
+///
+/// The walk also ends if it takes 10,000 steps in a row without carving a new tile, so the target may not be reached; a strong bias can pin the walk against the bounds, far from the tiles it has not yet carved.
+///
+/// The blob is a [`BitmaskShape`](struct.BitmaskShape.html) over the smallest `Area` holding every carved tile, where a tile is contained only if all eight of its neighbours were carved. Blobs are generated by sampling the `DrunkardsWalk` with an `Rng`, or from a seed.
+/// ```
+/// # use dungen_minion_geometry::*;
+/// use rand::{thread_rng, Rng};
+///
+/// let walk = DrunkardsWalk::new(Position::new(0, 0), CountRange::new(150, 200), None, None);
+/// let blob = walk.generate_from_seed(11);
+/// assert!(blob == walk.generate_from_seed(11));
+/// let tile_count = blob.count_tiles().total();
+/// assert!(tile_count >= 150 && tile_count <= 200);
+/// assert!(blob.intersects_position(Position::new(0, 0)));
+///
+/// // Tiles next to the outside of the blob are its walls.
+/// for (position, containment) in blob.tiles() {
+///     let is_wall = Connectivity::Eight
+///         .offsets()
+///         .iter()
+///         .any(|offset| !blob.intersects_position(position + *offset));
+///     assert!(is_wall == (containment == Containment::Intersects));
+/// }
+///
+/// // A bias makes the walk drift in its direction.
+/// let eastward = DrunkardsWalk::new(
+///     Position::new(0, 0),
+///     CountRange::new(200, 200),
+///     Some((CardinalDirection::East, 4.0)),
+///     None,
+/// );
+/// let blob = eastward.generate_from_seed(5);
+/// assert!(blob.right() > -blob.left());
+///
+/// // Bounds keep the walk inside them, and cap the target.
+/// let bounds = Area::new(Position::new(-3, -3), Size::new(7, 7));
+/// let bounded = DrunkardsWalk::new(Position::new(40, 0), CountRange::new(100, 100), None, Some(bounds));
+/// let blob = thread_rng().sample(bounded);
+/// assert!(blob.tiles().all(|(position, _)| bounds.intersects_position(position)));
+/// assert!(blob.count_tiles().total() == 49);
+/// assert!(blob.contains_position(Position::new(0, 0)) == Containment::Contains);
+///
+/// // A strong bias may pin the walk against the bounds before it reaches the target.
+/// let bounds = Area::new(Position::new(0, 0), Size::new(20, 20));
+/// for weight in [3.0, f64::INFINITY].iter() {
+///     let pinned = DrunkardsWalk::new(
+///         Position::new(10, 10),
+///         CountRange::new(300, 400),
+///         Some((CardinalDirection::East, *weight)),
+///         Some(bounds),
+///     );
+///     let blob = pinned.generate_from_seed(9);
+///     assert!(blob.count_tiles().total() <= 400);
+///     assert!(blob.tiles().all(|(position, _)| bounds.intersects_position(position)));
+/// }
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DrunkardsWalk {
+    start: Position,
+    target: CountRange,
+    bias: Option<(CardinalDirection, f64)>,
+    bounds: Option<Area>,
+}
+
+impl DrunkardsWalk {
+    /// Creates a new `DrunkardsWalk` from the `Position` it starts at and the range of the number of tiles it carves.
+    ///
+    /// The bias, if any, is the direction to drift in, and the weight added to that direction. The bounds, if any, are the `Area` the walk stays inside.
+    pub fn new(
+        start: Position,
+        target: CountRange,
+        bias: Option<(CardinalDirection, f64)>,
+        bounds: Option<Area>,
+    ) -> Self {
+        Self {
+            start,
+            target,
+            bias,
+            bounds,
+        }
+    }
+
+    /// Returns the direction the walk drifts in, and the weight added to that direction.
+    pub fn bias(&self) -> Option<(CardinalDirection, f64)> {
+        self.bias
+    }
+
+    /// Returns the `Area` the walk stays inside, if any.
+    pub fn bounds(&self) -> Option<Area> {
+        self.bounds
+    }
+
+    /// Generates a blob from the seed; the same seed always generates the same blob, on every platform.
+    pub fn generate_from_seed(&self, seed: u64) -> BitmaskShape {
+        self.sample(&mut ChaCha8Rng::seed_from_u64(seed))
+    }
+
+    /// Returns the `Position` the walk starts at.
+    pub fn start(&self) -> Position {
+        self.start
+    }
+
+    /// Returns the range of the number of tiles the walk carves.
+    pub fn target(&self) -> CountRange {
+        self.target
+    }
+}
+
+impl Distribution<BitmaskShape> for DrunkardsWalk {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> BitmaskShape {
+        let mut target = rng.sample(self.target);
+        let mut position = self.start;
+        if let Some(bounds) = self.bounds {
+            target = target.min(bounds.width() as Count * bounds.height() as Count);
+            position = Position::new(
+                position.x().max(bounds.left()).min(bounds.right()),
+                position.y().max(bounds.top()).min(bounds.bottom()),
+            );
+        }
+
+        let directions = [
+            CardinalDirection::North,
+            CardinalDirection::East,
+            CardinalDirection::South,
+            CardinalDirection::West,
+        ];
+        let weights: Vec<f64> = directions
+            .iter()
+            .map(|direction| match self.bias {
+                // Weights are kept finite, so that they can be summed and rolled against.
+                Some((bias, weight)) if bias == *direction && weight > 0.0 => {
+                    1.0 + weight.min(f64::MAX)
+                }
+                _ => 1.0,
+            })
+            .collect();
+        let total_weight: f64 = weights.iter().sum();
+
+        let mut carved = HashSet::new();
+        if target > 0 {
+            carved.insert(position);
+        }
+        let mut idle_steps = 0;
+        while carved.len() < target && idle_steps < MAX_IDLE_STEPS {
+            let mut roll = rng.gen::<f64>() * total_weight;
+            let mut step = directions[directions.len() - 1];
+            for (direction, weight) in directions.iter().zip(weights.iter()) {
+                if roll < *weight {
+                    step = *direction;
+                    break;
+                }
+                roll -= weight;
+            }
+
+            let next = position + Position::from(step);
+            let is_in_bounds = match self.bounds {
+                Some(bounds) => bounds.intersects_position(next),
+                None => true,
+            };
+            idle_steps += 1;
+            if is_in_bounds {
+                position = next;
+                if carved.insert(position) {
+                    idle_steps = 0;
+                }
+            }
+        }
+
+        let area = if carved.is_empty() {
+            Area::new(self.start, Size::zero())
+        } else {
+            let left = carved.iter().map(|position| position.x()).min().unwrap();
+            let top = carved.iter().map(|position| position.y()).min().unwrap();
+            let mut area = Area::new(Position::new(left, top), Size::zero());
+            area.right_set(carved.iter().map(|position| position.x()).max().unwrap());
+            area.bottom_set(carved.iter().map(|position| position.y()).max().unwrap());
+            area
+        };
+
+        let mut bitmask = BitmaskShape::new(area);
+        for position in carved.iter() {
+            bitmask.set_containment(*position, Containment::Intersects);
+        }
+        bitmask.recalculate_containment();
+        bitmask
+    }
+}
+
+impl ProvidesPlacedShape for DrunkardsWalk {
+    fn provide_placed_shape(&self) -> Box<dyn PlacedShape> {
+        Box::new(self.sample(&mut thread_rng()))
+    }
+}
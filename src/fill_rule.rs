@@ -0,0 +1,16 @@
+// External includes.
+
+// Standard includes.
+
+// Internal includes.
+
+/// Defines which tiles are inside a [`PolygonShape`](struct.PolygonShape.html) whose edges cross or overlap.
+///
+/// Both rules agree for polygons whose edges do not cross.
+#[derive(Copy, Clone, Debug, Display, Eq, Hash, PartialEq)]
+pub enum FillRule {
+    /// A tile is inside if a ray from it crosses the edges an odd number of times.
+    EvenOdd,
+    /// A tile is inside if the edges wind around it a non-zero number of times.
+    NonZero,
+}
@@ -0,0 +1,143 @@
+// External includes.
+
+// Standard includes.
+
+// Internal includes.
+use super::Count;
+
+/// A deterministic, seeded, two-dimensional gradient noise function, in the style of Perlin noise.
+///
+/// Noise is made of one or more octaves. The first octave has the given frequency, and each octave after it has twice the frequency and half the weight of the octave before it. The result is scaled back to between `-1.0` and `1.0`, and changes smoothly between nearby points; the same seed always gives the same noise.
+/// ```
+/// # use dungen_minion_geometry::*;
+/// let noise = GradientNoise::new(42, 4, 0.1);
+/// assert!(noise.value_at(3.5, -7.25) == GradientNoise::new(42, 4, 0.1).value_at(3.5, -7.25));
+/// assert!(noise.seed() == 42 && noise.octaves() == 4 && noise.frequency() == 0.1);
+///
+/// // Different seeds give different noise.
+/// let other = GradientNoise::new(43, 4, 0.1);
+/// assert!((0..100).any(|x| noise.value_at(x as f64, 0.0) != other.value_at(x as f64, 0.0)));
+///
+/// for y in -50..50 {
+///     for x in -50..50 {
+///         let (x, y) = (x as f64 * 1.37, y as f64 * 0.91);
+///         let value = noise.value_at(x, y);
+///         assert!(value >= -1.0 && value <= 1.0);
+///         // Nearby points have nearby values.
+///         assert!((noise.value_at(x + 0.01, y) - value).abs() < 0.05);
+///         assert!((noise.value_at(x, y + 0.01) - value).abs() < 0.05);
+///     }
+/// }
+///
+/// // A single octave is zero on the corners of its grid.
+/// let single = GradientNoise::new(7, 1, 0.25);
+/// assert!(single.value_at(8.0, -4.0) == 0.0);
+/// assert!(single.value_at(6.0, -4.0) != 0.0);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GradientNoise {
+    seed: u64,
+    octaves: Count,
+    frequency: f64,
+}
+
+impl GradientNoise {
+    /// Creates a new `GradientNoise` from a seed, the number of octaves, and the frequency of the first octave.
+    pub fn new(seed: u64, octaves: Count, frequency: f64) -> Self {
+        Self {
+            seed,
+            octaves,
+            frequency,
+        }
+    }
+
+    /// Returns the frequency of the first octave.
+    pub fn frequency(&self) -> f64 {
+        self.frequency
+    }
+
+    /// Returns the number of octaves.
+    pub fn octaves(&self) -> Count {
+        self.octaves
+    }
+
+    /// Returns the seed.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns the value of the noise at the given point, between `-1.0` and `1.0`.
+    ///
+    /// No octaves gives a value of `0.0` everywhere.
+    pub fn value_at(&self, x: f64, y: f64) -> f64 {
+        let mut total = 0.0;
+        let mut total_weight = 0.0;
+        let mut frequency = self.frequency;
+        let mut weight = 1.0;
+        for octave in 0..self.octaves {
+            let seed = self.seed.wrapping_add(octave as u64);
+            total += octave_value_at(seed, x * frequency, y * frequency) * weight;
+            total_weight += weight;
+            frequency *= 2.0;
+            weight *= 0.5;
+        }
+
+        if total_weight > 0.0 {
+            (total / total_weight).clamp(-1.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Returns the value of a single octave of noise, with grid corners on whole numbers.
+fn octave_value_at(seed: u64, x: f64, y: f64) -> f64 {
+    let left = x.floor();
+    let top = y.floor();
+    let (offset_x, offset_y) = (x - left, y - top);
+    let (left, top) = (left as i64, top as i64);
+
+    let corner = |corner_x: i64, corner_y: i64| {
+        let (gradient_x, gradient_y) = gradient(seed, left + corner_x, top + corner_y);
+        (gradient_x * (offset_x - corner_x as f64)) + (gradient_y * (offset_y - corner_y as f64))
+    };
+    let fade_x = fade(offset_x);
+    let fade_y = fade(offset_y);
+    let upper = lerp(corner(0, 0), corner(1, 0), fade_x);
+    let lower = lerp(corner(0, 1), corner(1, 1), fade_x);
+    lerp(upper, lower, fade_y)
+}
+
+/// Returns the gradient at a grid corner; one of the eight directions to the sides and the corners of a square.
+fn gradient(seed: u64, x: i64, y: i64) -> (f64, f64) {
+    const GRADIENTS: [(f64, f64); 8] = [
+        (1.0, 0.0),
+        (1.0, 1.0),
+        (0.0, 1.0),
+        (-1.0, 1.0),
+        (-1.0, 0.0),
+        (-1.0, -1.0),
+        (0.0, -1.0),
+        (1.0, -1.0),
+    ];
+
+    let hash = mix(seed ^ mix((x as u64) ^ mix(y as u64)));
+    GRADIENTS[(hash % GRADIENTS.len() as u64) as usize]
+}
+
+/// Scrambles the bits of a value, as by the SplitMix64 finalizer.
+fn mix(value: u64) -> u64 {
+    let mut value = value.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    value ^ (value >> 31)
+}
+
+/// Eases the offset within a grid square, so that the noise is smooth across grid lines.
+fn fade(t: f64) -> f64 {
+    t * t * t * ((t * ((t * 6.0) - 15.0)) + 10.0)
+}
+
+fn lerp(from: f64, to: f64, t: f64) -> f64 {
+    from + ((to - from) * t)
+}
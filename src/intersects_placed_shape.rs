@@ -3,12 +3,111 @@
 // Standard includes.
 
 // Internal includes.
-use super::{IntersectsShape, PlacedShape};
+use super::{
+    Area, HasArea, HasBottom, HasLeft, HasRight, HasTop, IntersectsPosition, IntersectsShape,
+    PlacedShape, Position,
+};
 
 /// Defines an intersection operation between this instance and a [`PlacedShape`](trait.PlacedShape.html).
-pub trait IntersectsPlacedShape: IntersectsShape {
+///
+/// Intersection is tile-accurate; two shapes intersect only if at least one tile intersects both of them. The answer does not depend on which of the two shapes is tested against the other.
+/// ```
+/// # use dungen_minion_geometry::*;
+/// let room = Area::new(Position::new(0, 0), Size::new(6, 6));
+/// let touching_room = Area::new(Position::new(5, 5), Size::new(6, 6));
+/// let distant_room = Area::new(Position::new(6, 0), Size::new(6, 6));
+/// assert!(room.intersects_placed_shape(&touching_room));
+/// assert!(!room.intersects_placed_shape(&distant_room));
+///
+/// // The corners of an oval's bounding area are outside of the oval.
+/// let oval = Oval::new(Position::new(0, 0), Size::new(9, 9));
+/// let corner = Area::new(Position::new(-2, -2), Size::new(3, 3));
+/// let side = Area::new(Position::new(-2, 3), Size::new(3, 3));
+/// assert!(!oval.intersects_placed_shape(&corner));
+/// assert!(!corner.intersects_placed_shape(&oval));
+/// assert!(oval.intersects_placed_shape(&side));
+/// assert!(side.intersects_placed_shape(&oval));
+///
+/// // An inverted shape intersects everything outside of its interior.
+/// let inverted_room = InvertPlacedShape::new(room);
+/// let inner_room = Area::new(Position::new(1, 1), Size::new(4, 4));
+/// assert!(inverted_room.intersects_placed_shape(&distant_room));
+/// assert!(!inverted_room.intersects_placed_shape(&inner_room));
+/// assert!(!inner_room.intersects_placed_shape(&inverted_room));
+///
+/// // The order of the shapes does not matter, with an inverted shape on either side.
+/// let small_room = Area::new(Position::new(0, 0), Size::new(3, 3));
+/// let inverted_distant = InvertPlacedShape::new(Area::new(Position::new(100, 100), Size::new(3, 3)));
+/// assert!(small_room.intersects_placed_shape(&inverted_distant));
+/// assert!(inverted_distant.intersects_placed_shape(&small_room));
+/// assert!(inverted_room.intersects_placed_shape(&inverted_distant));
+/// assert!(inverted_distant.intersects_placed_shape(&inverted_room));
+///
+/// let values: Box<[(Inclusion, Box<dyn PlacedShape>); 2]> = Box::new([
+///     (Inclusion::Include, Box::new(room)),
+///     (Inclusion::Include, Box::new(Area::new(Position::new(10, 0), Size::new(6, 6)))),
+/// ]);
+/// let rooms = PlacedShapeSlice::new(values);
+/// assert!(rooms.intersects_placed_shape(&Area::new(Position::new(10, 5), Size::new(1, 1))));
+/// assert!(!rooms.intersects_placed_shape(&Area::new(Position::new(7, 0), Size::new(2, 6))));
+///
+/// // Shapes provided as `Box<dyn PlacedShape>` can be tested, too.
+/// let provided: Box<dyn PlacedShape> = room.provide_placed_shape();
+/// assert!(provided.intersects_placed_shape(&oval));
+/// ```
+pub trait IntersectsPlacedShape: HasArea + IntersectsPosition + IntersectsShape {
     /// Returns true if the given 'PlacedShape' at is within the boundaries of this instance.
     fn intersects_placed_shape(&self, placed_shape: &dyn PlacedShape) -> bool {
-        self.intersects_shape(placed_shape.position(), placed_shape.as_shape())
+        let bounds = match placed_shape_bounds(self.area(), placed_shape) {
+            Some(bounds) => bounds,
+            None => return false,
+        };
+
+        rasterized_intersects_placed_shape(
+            &bounds,
+            |position| self.intersects_position(position),
+            placed_shape,
+        )
+    }
+
+    /// Returns true if every tile which intersects this instance is inside of its [`Area`](struct.Area.html).
+    ///
+    /// Shapes which extend infinitely, such as an [`InvertPlacedShape`](struct.InvertPlacedShape.html), are not bounded.
+    fn is_bounded(&self) -> bool {
+        true
+    }
+}
+
+/// Returns the part of `bounds` where the other `PlacedShape` may intersect, or `None` if there is no such part.
+///
+/// A bounded `PlacedShape` can only intersect inside of its `Area`; one which is not bounded may intersect anywhere.
+pub(crate) fn placed_shape_bounds(bounds: &Area, placed_shape: &dyn PlacedShape) -> Option<Area> {
+    if placed_shape.is_bounded() {
+        bounds.intersection(placed_shape.area())
+    } else {
+        Some(*bounds)
     }
 }
+
+/// The rasterizing fallback for [`IntersectsPlacedShape`](trait.IntersectsPlacedShape.html).
+///
+/// Tests every tile within `bounds`, until a tile is found which intersects both this instance and the other `PlacedShape`.
+pub(crate) fn rasterized_intersects_placed_shape<TIntersectsPosition>(
+    bounds: &Area,
+    intersects_position: TIntersectsPosition,
+    placed_shape: &dyn PlacedShape,
+) -> bool
+where
+    TIntersectsPosition: Fn(Position) -> bool,
+{
+    for y in bounds.top()..=bounds.bottom() {
+        for x in bounds.left()..=bounds.right() {
+            let position = Position::new(x, y);
+            if intersects_position(position) && placed_shape.intersects_position(position) {
+                return true;
+            }
+        }
+    }
+
+    false
+}
@@ -3,10 +3,48 @@
 // Standard includes.
 
 // Internal includes.
-use super::{Position, Shape};
+use super::{Area, HasBottom, HasLeft, HasRight, HasTop, Position, Shape};
 
 /// Defines an intersection operation between this instance and a [`Shape`](trait.Shape.html) at a specific [`Position`](struct.Position.html).
+///
+/// The other `Shape` is only sampled within the bounds of its [`Size`](struct.Size.html), placed at the given offset.
 pub trait IntersectsShape {
     /// Returns true if the given 'Shape' at the given `Position` offset is within the boundaries of this instance.
     fn intersects_shape(&self, other_offset: &Position, other_shape: &dyn Shape) -> bool;
 }
+
+/// Returns the [`Area`](struct.Area.html) covered by the given `Shape` when placed at the given offset.
+pub(crate) fn placed_shape_area(other_offset: &Position, other_shape: &dyn Shape) -> Area {
+    Area::new(*other_offset, *other_shape.size())
+}
+
+/// The rasterizing fallback for [`IntersectsShape`](trait.IntersectsShape.html).
+///
+/// Tests every tile within both `bounds` and the bounds of the other `Shape`, until a tile is found which intersects both this instance and the other `Shape`.
+pub(crate) fn rasterized_intersects_shape<TIntersectsPosition>(
+    bounds: &Area,
+    intersects_position: TIntersectsPosition,
+    other_offset: &Position,
+    other_shape: &dyn Shape,
+) -> bool
+where
+    TIntersectsPosition: Fn(Position) -> bool,
+{
+    let overlap = match bounds.intersection(&placed_shape_area(other_offset, other_shape)) {
+        Some(overlap) => overlap,
+        None => return false,
+    };
+
+    for y in overlap.top()..=overlap.bottom() {
+        for x in overlap.left()..=overlap.right() {
+            let position = Position::new(x, y);
+            if other_shape.intersects_local_position(position - *other_offset)
+                && intersects_position(position)
+            {
+                return true;
+            }
+        }
+    }
+
+    false
+}
@@ -3,10 +3,13 @@
 // Standard includes.
 
 // Internal includes.
+use super::intersects_placed_shape::rasterized_intersects_placed_shape;
+use super::intersects_shape::{placed_shape_area, rasterized_intersects_shape};
 use super::{
-    Area, Containment, ContainsLocalPosition, ContainsPosition, HasArea, HasPosition, HasSize,
-    IntersectsLocalPosition, IntersectsPosition, IsArea, IsSize, Placed, PlacedObject, PlacedShape,
-    Position, ProvidesArea, ProvidesPlacedShape, ProvidesSize, Shape, Size,
+    Area, Containment, ContainsLocalPosition, ContainsPosition, CountsTiles, HasArea, HasPosition,
+    HasSize, IntersectsLocalPosition, IntersectsPlacedShape, IntersectsPosition, IntersectsShape,
+    IsArea, IsSize, Placed, PlacedObject, PlacedShape, Position, ProvidesArea, ProvidesPlacedShape,
+    ProvidesSize, Shape, Size,
 };
 
 /// Inverts the [`Containment`](enum.Containment.html) and intersection of the contained [`PlacedShape`](trait.PlacedShape.html).
@@ -66,6 +69,11 @@ impl<TPlacedShape: 'static> ContainsPosition for InvertPlacedShape<TPlacedShape>
 {
 }
 
+impl<TPlacedShape: 'static> CountsTiles for InvertPlacedShape<TPlacedShape> where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized
+{
+}
+
 impl<TPlacedShape: 'static> HasArea for InvertPlacedShape<TPlacedShape>
 where
     TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
@@ -114,11 +122,49 @@ where
     }
 }
 
+impl<TPlacedShape: 'static> IntersectsPlacedShape for InvertPlacedShape<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    fn intersects_placed_shape(&self, placed_shape: &dyn PlacedShape) -> bool {
+        // Two inverted shapes always meet, far enough from both of their bounds.
+        if !placed_shape.is_bounded() {
+            return true;
+        }
+
+        // Every tile outside of the inner shape's bounds intersects this instance, so only the tiles of the other `PlacedShape` need searching.
+        rasterized_intersects_placed_shape(
+            placed_shape.area(),
+            |position| self.intersects_position(position),
+            placed_shape,
+        )
+    }
+
+    fn is_bounded(&self) -> bool {
+        false
+    }
+}
+
 impl<TPlacedShape: 'static> IntersectsPosition for InvertPlacedShape<TPlacedShape> where
     TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized
 {
 }
 
+impl<TPlacedShape: 'static> IntersectsShape for InvertPlacedShape<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    fn intersects_shape(&self, other_offset: &Position, other_shape: &dyn Shape) -> bool {
+        // An inverted shape extends infinitely, so only the other `Shape` bounds the search.
+        rasterized_intersects_shape(
+            &placed_shape_area(other_offset, other_shape),
+            |position| self.intersects_position(position),
+            other_offset,
+            other_shape,
+        )
+    }
+}
+
 impl<TPlacedShape: 'static> IsArea for InvertPlacedShape<TPlacedShape> where
     TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized
 {
@@ -0,0 +1,53 @@
+// External includes.
+
+// Standard includes.
+
+// Internal includes.
+use super::{Containment, Position, Shape, TileIter};
+
+/// Provides iterators over the tiles of a [`Shape`](trait.Shape.html), in local coordinates.
+///
+/// Each tile is yielded with its [`Containment`](enum.Containment.html). Tiles which are `Containment::Disjoint` are never yielded.
+/// ```
+/// # use dungen_minion_geometry::*;
+/// let area = Area::new(Position::new(5, 5), Size::new(4, 3));
+///
+/// assert!(area.local_tiles().count() == 12);
+/// assert!(area.local_edge_tiles().count() == 10);
+/// assert!(
+///     area.local_interior_tiles().collect::<Vec<_>>()
+///         == vec![
+///             (Position::new(1, 1), Containment::Contains),
+///             (Position::new(2, 1), Containment::Contains),
+///         ]
+/// );
+///
+/// // Tiles of shapes which do not fill their bounds are skipped.
+/// let oval = Oval::new(Position::new(0, 0), Size::new(9, 9));
+/// assert!(oval.local_tiles().count() < 81);
+/// assert!(oval
+///     .local_tiles()
+///     .all(|(position, containment)| oval.contains_local_position(position) == containment));
+///
+/// // Works on trait objects, too.
+/// let shape: &dyn Shape = &Size::new(3, 3);
+/// assert!(shape.local_tiles().count() == 9);
+/// ```
+pub trait IteratesLocalTiles: Shape {
+    /// Returns an iterator over every tile which intersects this instance, in local coordinates.
+    fn local_tiles(&self) -> TileIter<'_, Self> {
+        TileIter::new(self, Position::zero(), None)
+    }
+
+    /// Returns an iterator over every tile which is entirely contained within this instance, in local coordinates.
+    fn local_interior_tiles(&self) -> TileIter<'_, Self> {
+        TileIter::new(self, Position::zero(), Some(Containment::Contains))
+    }
+
+    /// Returns an iterator over every tile which is on the edge of this instance, in local coordinates.
+    fn local_edge_tiles(&self) -> TileIter<'_, Self> {
+        TileIter::new(self, Position::zero(), Some(Containment::Intersects))
+    }
+}
+
+impl<TShape> IteratesLocalTiles for TShape where TShape: Shape + ?Sized {}
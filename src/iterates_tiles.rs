@@ -0,0 +1,52 @@
+// External includes.
+
+// Standard includes.
+
+// Internal includes.
+use super::{Containment, IteratesLocalTiles, PlacedShape, TileIter};
+
+/// Provides iterators over the tiles of a [`PlacedShape`](trait.PlacedShape.html), in world coordinates.
+///
+/// Each tile is yielded with its [`Containment`](enum.Containment.html). Tiles which are `Containment::Disjoint` are never yielded.
+/// ```
+/// # use dungen_minion_geometry::*;
+/// let area = Area::new(Position::new(5, 5), Size::new(4, 3));
+///
+/// assert!(area.tiles().count() == 12);
+/// assert!(area.edge_tiles().count() == 10);
+/// assert!(
+///     area.interior_tiles().collect::<Vec<_>>()
+///         == vec![
+///             (Position::new(6, 6), Containment::Contains),
+///             (Position::new(7, 6), Containment::Contains),
+///         ]
+/// );
+///
+/// let oval = Oval::new(Position::new(-4, -4), Size::new(9, 9));
+/// assert!(oval
+///     .tiles()
+///     .all(|(position, containment)| oval.contains_position(position) == containment));
+/// assert!(oval.tiles().any(|(position, _)| position == Position::new(0, 0)));
+///
+/// // Works on trait objects, too.
+/// let placed_shape: Box<dyn PlacedShape> = area.provide_placed_shape();
+/// assert!(placed_shape.tiles().count() == 12);
+/// ```
+pub trait IteratesTiles: IteratesLocalTiles + PlacedShape {
+    /// Returns an iterator over every tile which intersects this instance, in world coordinates.
+    fn tiles(&self) -> TileIter<'_, Self> {
+        TileIter::new(self, *self.position(), None)
+    }
+
+    /// Returns an iterator over every tile which is entirely contained within this instance, in world coordinates.
+    fn interior_tiles(&self) -> TileIter<'_, Self> {
+        TileIter::new(self, *self.position(), Some(Containment::Contains))
+    }
+
+    /// Returns an iterator over every tile which is on the edge of this instance, in world coordinates.
+    fn edge_tiles(&self) -> TileIter<'_, Self> {
+        TileIter::new(self, *self.position(), Some(Containment::Intersects))
+    }
+}
+
+impl<TPlacedShape> IteratesTiles for TPlacedShape where TPlacedShape: PlacedShape + ?Sized {}
@@ -14,13 +14,32 @@ extern crate derive_more;
 // Internal includes.
 mod area;
 mod area_range;
+mod ascii_palette;
+mod ascii_renderer;
+mod bitmask_shape;
+mod boolean_operation;
+mod boolean_placed_shape;
+mod capsule;
 mod cardinal_direction;
 mod cardinal_rotation;
+mod cardinal_transform;
+mod cave_generator;
+mod circle;
+mod connectivity;
 mod containment;
 mod contains_local_position;
 mod contains_position;
+mod corner_style;
+mod cornered_area;
 mod count_range;
+mod counts_tiles;
+mod cross;
 mod defines;
+mod diamond;
+mod distance_metric;
+mod drunkards_walk;
+mod fill_rule;
+mod gradient_noise;
 mod has_area;
 mod has_bottom;
 mod has_height;
@@ -39,12 +58,19 @@ mod invert_placed_shape;
 mod is_area;
 mod is_position;
 mod is_size;
+mod iterates_local_tiles;
+mod iterates_tiles;
+mod line_cap;
+mod line_shape;
+mod noise_shape;
 mod oval;
+mod parse_ascii_error;
+mod perturbed_oval;
 mod placed;
 mod placed_object;
 mod placed_shape;
 mod placed_shape_slice;
-#[doc(inline)]
+mod polygon_shape;
 mod position;
 mod position_range;
 mod provides_area;
@@ -53,19 +79,49 @@ mod provides_placed_shape;
 mod provides_position;
 mod provides_shape;
 mod provides_size;
+mod radius_rounding;
+mod region_retention;
+mod renders_ascii;
+mod ring;
+mod rotated_shape;
+mod sector;
 mod shape;
 mod size;
 mod size_range;
+mod tile_count;
+mod tile_iter;
+mod traces_perimeter;
+mod transformed_shape;
+mod voronoi_partition;
 
 pub use area::Area;
 pub use area_range::AreaRange;
+pub use ascii_palette::AsciiPalette;
+pub use ascii_renderer::AsciiRenderer;
+pub use bitmask_shape::BitmaskShape;
+pub use boolean_operation::BooleanOperation;
+pub use boolean_placed_shape::BooleanPlacedShape;
+pub use capsule::Capsule;
 pub use cardinal_direction::CardinalDirection;
 pub use cardinal_rotation::CardinalRotation;
+pub use cardinal_transform::CardinalTransform;
+pub use cave_generator::CaveGenerator;
+pub use circle::Circle;
+pub use connectivity::Connectivity;
 pub use containment::Containment;
 pub use contains_local_position::ContainsLocalPosition;
 pub use contains_position::ContainsPosition;
+pub use corner_style::CornerStyle;
+pub use cornered_area::CorneredArea;
 pub use count_range::CountRange;
+pub use counts_tiles::CountsTiles;
+pub use cross::Cross;
 pub use defines::{Coord, Count, Length};
+pub use diamond::Diamond;
+pub use distance_metric::DistanceMetric;
+pub use drunkards_walk::DrunkardsWalk;
+pub use fill_rule::FillRule;
+pub use gradient_noise::GradientNoise;
 pub use has_area::HasArea;
 pub use has_bottom::HasBottom;
 pub use has_height::HasHeight;
@@ -84,11 +140,19 @@ pub use invert_placed_shape::InvertPlacedShape;
 pub use is_area::IsArea;
 pub use is_position::IsPosition;
 pub use is_size::IsSize;
+pub use iterates_local_tiles::IteratesLocalTiles;
+pub use iterates_tiles::IteratesTiles;
+pub use line_cap::LineCap;
+pub use line_shape::LineShape;
+pub use noise_shape::NoiseShape;
 pub use oval::Oval;
+pub use parse_ascii_error::ParseAsciiError;
+pub use perturbed_oval::PerturbedOval;
 pub use placed::Placed;
 pub use placed_object::PlacedObject;
 pub use placed_shape::PlacedShape;
 pub use placed_shape_slice::PlacedShapeSlice;
+pub use polygon_shape::PolygonShape;
 pub use position::Position;
 pub use position_range::PositionRange;
 pub use provides_area::ProvidesArea;
@@ -97,9 +161,20 @@ pub use provides_placed_shape::ProvidesPlacedShape;
 pub use provides_position::ProvidesPosition;
 pub use provides_shape::ProvidesShape;
 pub use provides_size::ProvidesSize;
+pub use radius_rounding::RadiusRounding;
+pub use region_retention::RegionRetention;
+pub use renders_ascii::RendersAscii;
+pub use ring::Ring;
+pub use rotated_shape::RotatedShape;
+pub use sector::Sector;
 pub use shape::Shape;
 pub use size::Size;
 pub use size_range::SizeRange;
+pub use tile_count::TileCount;
+pub use tile_iter::TileIter;
+pub use traces_perimeter::TracesPerimeter;
+pub use transformed_shape::TransformedShape;
+pub use voronoi_partition::VoronoiPartition;
 
 #[cfg(test)]
 mod tests {
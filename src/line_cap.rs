@@ -0,0 +1,14 @@
+// External includes.
+
+// Standard includes.
+
+// Internal includes.
+
+/// Defines the shape of the ends of a [`LineShape`](struct.LineShape.html).
+#[derive(Copy, Clone, Debug, Display, Eq, Hash, PartialEq)]
+pub enum LineCap {
+    /// The line ends in a square as wide as the line, aligned to the grid.
+    Square,
+    /// The line ends in a circle as wide as the line.
+    Round,
+}
@@ -0,0 +1,268 @@
+// External includes.
+
+// Standard includes.
+
+// Internal includes.
+use super::{
+    Area, BitmaskShape, Containment, ContainsLocalPosition, ContainsPosition, Coord, CountsTiles,
+    HasArea, HasBottom, HasPosition, HasRight, HasSize, IntersectsLocalPosition,
+    IntersectsPlacedShape, IntersectsPosition, IntersectsShape, IsArea, IsPosition, IsSize,
+    IteratesTiles, Length, LineCap, Oval, Placed, PlacedObject, PlacedShape, Position,
+    ProvidesArea, ProvidesPlacedShape, ProvidesSize, Shape, Size, TileCount,
+};
+
+/// Defines a straight line between two [`Position`](struct.Position.html)s, with a thickness in tiles.
+///
+/// The line is drawn Bresenham-style, by stamping a circular brush as wide as the line at each tile of a one-tile line between the two `Position`s; each end is then stamped with a brush shaped as by its [`LineCap`](enum.LineCap.html). Where the thickness is even, the brush extends one tile further right and down than left and up. A tile is contained only if all eight of its neighbours intersect, so the sides of the line report [`Containment::Intersects`](enum.Containment.html).
+///
+/// The line is rasterized once, when it is created.
+/// ```
+/// # use dungen_minion_geometry::*;
+/// let corridor = LineShape::new(Position::new(0, 0), Position::new(6, 0), 3, LineCap::Square);
+/// assert!(*corridor.area() == Area::new(Position::new(-1, -1), Size::new(9, 3)));
+/// assert!(
+///     corridor.render_ascii(&AsciiPalette::default())
+///         == concat!(
+///             "#########\n",
+///             "#.......#\n",
+///             "#########\n",
+///         )
+/// );
+///
+/// // Round ends cut the corners off thick lines.
+/// let square = LineShape::new(Position::new(0, 0), Position::new(8, 0), 5, LineCap::Square);
+/// let round = LineShape::new(Position::new(0, 0), Position::new(8, 0), 5, LineCap::Round);
+/// assert!(square.intersects_position(Position::new(-2, -2)));
+/// assert!(!round.intersects_position(Position::new(-2, -2)));
+/// assert!(round.intersects_position(Position::new(0, -2)));
+/// assert!(round.contains_position(Position::new(4, -2)) == Containment::Intersects);
+/// assert!(round.contains_position(Position::new(4, -1)) == Containment::Contains);
+///
+/// // A line one tile thick has one tile for each step along its longer axis.
+/// let river = LineShape::new(Position::new(0, 0), Position::new(7, -3), 1, LineCap::Round);
+/// assert!(river.tiles().count() == 8);
+/// assert!(river.intersects_position(Position::new(0, 0)));
+/// assert!(river.intersects_position(Position::new(7, -3)));
+/// assert!(river.interior_tiles().count() == 0);
+///
+/// // Lines can be combined with rooms.
+/// let values: Box<[(Inclusion, Box<dyn PlacedShape>); 2]> = Box::new([
+///     (Inclusion::Include, Box::new(Area::new(Position::new(-5, -2), Size::new(5, 5)))),
+///     (Inclusion::Include, Box::new(corridor.clone())),
+/// ]);
+/// let level = PlacedShapeSlice::new(values);
+/// assert!(level.intersects_position(Position::new(-5, 0)));
+/// assert!(level.intersects_position(Position::new(7, 0)));
+///
+/// // Moving the line moves its ends.
+/// let mut corridor = corridor;
+/// *corridor.position_mut() = Position::new(9, 9);
+/// assert!(corridor.start() == Position::new(10, 10));
+/// assert!(corridor.end() == Position::new(16, 10));
+/// ```
+#[derive(Clone, Debug)]
+pub struct LineShape {
+    bitmask: BitmaskShape,
+    local_start: Position,
+    local_end: Position,
+    thickness: Length,
+    cap: LineCap,
+}
+
+impl LineShape {
+    /// Creates a new `LineShape` from `start` to `end`, with the given thickness and ends.
+    ///
+    /// A thickness of zero creates a line with no tiles.
+    pub fn new(start: Position, end: Position, thickness: Length, cap: LineCap) -> Self {
+        // The offsets of the brush's edges from the tile it is stamped on.
+        let low = -((thickness as Coord - 1) / 2);
+        let high = low + thickness as Coord - 1;
+        let mut area = Area::new(
+            Position::new(start.x().min(end.x()) + low, start.y().min(end.y()) + low),
+            Size::zero(),
+        );
+        if thickness > 0 {
+            area.right_set(start.x().max(end.x()) + high);
+            area.bottom_set(start.y().max(end.y()) + high);
+        }
+
+        let mut bitmask = BitmaskShape::new(area);
+        if thickness > 0 {
+            let brush_area = Area::new(Position::new(low, low), Size::new(thickness, thickness));
+            let round_brush: Vec<Position> = Oval::new(*brush_area.position(), *brush_area.size())
+                .tiles()
+                .map(|(position, _)| position)
+                .collect();
+            let square_brush: Vec<Position> =
+                brush_area.tiles().map(|(position, _)| position).collect();
+            let cap_brush = match cap {
+                LineCap::Square => &square_brush,
+                LineCap::Round => &round_brush,
+            };
+
+            let mut stamp = |position: Position, brush: &[Position]| {
+                for offset in brush.iter() {
+                    bitmask.set_containment(position + *offset, Containment::Intersects);
+                }
+            };
+            for position in line_positions(start, end) {
+                stamp(position, &round_brush);
+            }
+            stamp(start, cap_brush);
+            stamp(end, cap_brush);
+            bitmask.recalculate_containment();
+        }
+
+        Self {
+            bitmask,
+            local_start: start - *area.position(),
+            local_end: end - *area.position(),
+            thickness,
+            cap,
+        }
+    }
+
+    /// Returns the `LineCap` used for the ends of the line.
+    pub fn cap(&self) -> LineCap {
+        self.cap
+    }
+
+    /// Returns the `Position` the line ends at.
+    pub fn end(&self) -> Position {
+        *self.position() + self.local_end
+    }
+
+    /// Returns the `Position` the line starts at.
+    pub fn start(&self) -> Position {
+        *self.position() + self.local_start
+    }
+
+    /// Returns the thickness of the line, in tiles.
+    pub fn thickness(&self) -> Length {
+        self.thickness
+    }
+}
+
+/// Returns the tiles of a one-tile line from `start` to `end`, inclusive, in order.
+///
+/// Each tile shares a side or a corner with the tile before it.
+pub(crate) fn line_positions(start: Position, end: Position) -> Vec<Position> {
+    let delta_x = (end.x() - start.x()).abs();
+    let delta_y = -(end.y() - start.y()).abs();
+    let step_x = if start.x() < end.x() { 1 } else { -1 };
+    let step_y = if start.y() < end.y() { 1 } else { -1 };
+
+    let mut output = Vec::with_capacity(delta_x.max(-delta_y) as usize + 1);
+    let mut error = delta_x + delta_y;
+    let mut position = start;
+    loop {
+        output.push(position);
+        if position == end {
+            break;
+        }
+
+        let doubled_error = 2 * error;
+        if doubled_error >= delta_y {
+            error += delta_y;
+            position += Position::new(step_x, 0);
+        }
+        if doubled_error <= delta_x {
+            error += delta_x;
+            position += Position::new(0, step_y);
+        }
+    }
+
+    output
+}
+
+impl ContainsLocalPosition for LineShape {
+    fn contains_local_position(&self, position: Position) -> Containment {
+        self.bitmask.contains_local_position(position)
+    }
+}
+
+impl ContainsPosition for LineShape {}
+
+impl CountsTiles for LineShape {
+    fn count_tiles(&self) -> TileCount {
+        self.bitmask.count_tiles()
+    }
+}
+
+impl HasArea for LineShape {
+    fn area(&self) -> &Area {
+        self.bitmask.area()
+    }
+
+    fn area_mut(&mut self) -> &mut Area {
+        self.bitmask.area_mut()
+    }
+}
+
+impl HasPosition for LineShape {
+    fn position(&self) -> &Position {
+        self.bitmask.position()
+    }
+
+    fn position_mut(&mut self) -> &mut Position {
+        self.bitmask.position_mut()
+    }
+}
+
+impl HasSize for LineShape {
+    fn size(&self) -> &Size {
+        self.bitmask.size()
+    }
+
+    fn size_mut(&mut self) -> &mut Size {
+        self.bitmask.size_mut()
+    }
+}
+
+impl IntersectsLocalPosition for LineShape {
+    fn intersects_local_position(&self, position: Position) -> bool {
+        self.bitmask.intersects_local_position(position)
+    }
+}
+
+impl IntersectsPlacedShape for LineShape {}
+
+impl IntersectsPosition for LineShape {}
+
+impl IntersectsShape for LineShape {
+    fn intersects_shape(&self, other_offset: &Position, other_shape: &dyn Shape) -> bool {
+        self.bitmask.intersects_shape(other_offset, other_shape)
+    }
+}
+
+impl IsArea for LineShape {}
+
+impl IsSize for LineShape {}
+
+impl Placed for LineShape {}
+
+impl PlacedObject for LineShape {}
+
+impl ProvidesArea for LineShape {
+    fn provide_area(&self) -> Area {
+        *self.area()
+    }
+}
+
+impl ProvidesPlacedShape for LineShape {
+    fn provide_placed_shape(&self) -> Box<dyn PlacedShape> {
+        Box::new(self.clone())
+    }
+}
+
+impl ProvidesSize for LineShape {
+    fn provide_size(&self) -> Size {
+        *self.size()
+    }
+}
+
+impl Shape for LineShape {
+    fn box_shape_clone(&self) -> Box<dyn Shape> {
+        Box::new((*self).clone())
+    }
+}
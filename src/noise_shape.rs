@@ -0,0 +1,280 @@
+// External includes.
+
+// Standard includes.
+use std::collections::VecDeque;
+
+// Internal includes.
+use super::{
+    Area, BitmaskShape, Connectivity, Containment, ContainsLocalPosition, ContainsPosition, Coord,
+    CountsTiles, GradientNoise, HasArea, HasHeight, HasPosition, HasSize, HasWidth,
+    IntersectsLocalPosition, IntersectsPlacedShape, IntersectsPosition, IntersectsShape, IsArea,
+    IsPosition, IsSize, Length, Placed, PlacedObject, PlacedShape, Position, ProvidesArea,
+    ProvidesPlacedShape, ProvidesSize, Shape, Size, TileCount,
+};
+
+/// Defines a shape by the tiles of an [`Area`](struct.Area.html) where [`GradientNoise`](struct.GradientNoise.html) reaches a threshold, such as a lake or a chasm.
+///
+/// The noise is sampled at the world position of each tile, so shapes made from the same `GradientNoise` agree where they overlap. A falloff mask [`PlacedShape`](trait.PlacedShape.html), such as an [`Oval`](struct.Oval.html), may be given to make the shape an island. The noise fades towards `-1.0` over the given number of tiles inward from the edge of the mask, and the tiles on the edge of the mask are never kept, so the shape stays strictly inside the mask. A tile is contained only if all eight of its neighbours are part of the shape.
+///
+/// The shape is rasterized once, when it is created.
+/// ```
+/// # use dungen_minion_geometry::*;
+/// let noise = GradientNoise::new(3, 3, 0.15);
+/// let area = Area::new(Position::new(0, 0), Size::new(30, 20));
+/// let chasm = NoiseShape::new(area, noise, 0.2);
+/// assert!(*chasm.area() == area);
+/// for (position, _) in area.tiles() {
+///     let value = noise.value_at(position.x() as f64, position.y() as f64);
+///     assert!(chasm.intersects_position(position) == (value >= 0.2));
+/// }
+///
+/// // A falloff mask makes an island inside the mask.
+/// let mask = Oval::new(Position::new(5, 2), Size::new(20, 16));
+/// let lake = NoiseShape::with_mask(&mask, noise, -0.1, 4);
+/// assert!(*lake.area() == *mask.area());
+/// assert!(lake.falloff() == 4);
+/// for (position, _) in lake.tiles() {
+///     assert!(mask.contains_position(position) == Containment::Contains);
+///     assert!(noise.value_at(position.x() as f64, position.y() as f64) >= -0.1);
+/// }
+///
+/// // Even with a threshold every tile reaches, the island stays strictly inside the mask.
+/// let flooded = NoiseShape::with_mask(&mask, noise, -1.0, 4);
+/// assert!(flooded.tiles().count() > 0);
+/// assert!(flooded
+///     .tiles()
+///     .all(|(position, _)| mask.contains_position(position) == Containment::Contains));
+/// assert!(NoiseShape::with_mask(&mask, noise, 1.1, 4).tiles().count() == 0);
+///
+/// // A wider falloff shrinks the island away from the edge of the mask.
+/// let sharp = NoiseShape::with_mask(&mask, noise, -0.3, 0);
+/// let soft = NoiseShape::with_mask(&mask, noise, -0.3, 8);
+/// assert!(soft.count_tiles().total() < sharp.count_tiles().total());
+/// assert!(soft.tiles().all(|(position, _)| sharp.intersects_position(position)));
+///
+/// // Lakes can be cut out of rooms, leaving a shore around them.
+/// let room = Area::new(Position::new(0, 0), Size::new(30, 20));
+/// let room_with_lake =
+///     BooleanPlacedShape::new(BooleanOperation::Difference, Box::new(room), Box::new(lake.clone()));
+/// for (position, containment) in lake.tiles() {
+///     if containment == Containment::Contains {
+///         assert!(room_with_lake.contains_position(position) == Containment::Disjoint);
+///     } else {
+///         assert!(room_with_lake.intersects_position(position));
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct NoiseShape {
+    bitmask: BitmaskShape,
+    noise: GradientNoise,
+    threshold: f64,
+    falloff: Length,
+}
+
+impl NoiseShape {
+    /// Creates a new `NoiseShape` of the tiles of the `Area` where the noise is at least the threshold.
+    pub fn new(area: Area, noise: GradientNoise, threshold: f64) -> Self {
+        Self::from_tiles(area, noise, threshold, 0, |_| 1.0)
+    }
+
+    /// Creates a new `NoiseShape` of the tiles inside of the falloff mask where the faded noise is at least the threshold.
+    ///
+    /// The falloff is the number of tiles inward from the edge of the mask over which the noise fades; with a falloff of zero, only the edge of the mask is cut away.
+    pub fn with_mask(
+        mask: &dyn PlacedShape,
+        noise: GradientNoise,
+        threshold: f64,
+        falloff: Length,
+    ) -> Self {
+        let area = *mask.area();
+        let width = area.width() as usize;
+        let height = area.height() as usize;
+
+        // Find the distance, in steps to any of the eight neighbours, of each tile of the mask from the outside of the mask; the edge of the mask is at a distance of one.
+        let mut distances = vec![0; width * height];
+        let mut queue = VecDeque::new();
+        for y in 0..height {
+            for x in 0..width {
+                let position = *area.position() + Position::new(x as Coord, y as Coord);
+                if mask.contains_position(position) == Containment::Intersects {
+                    distances[(y * width) + x] = 1;
+                    queue.push_back((x, y));
+                }
+            }
+        }
+        while let Some((x, y)) = queue.pop_front() {
+            let distance = distances[(y * width) + x];
+            for offset in Connectivity::Eight.offsets().iter() {
+                let next_x = x as Coord + offset.x();
+                let next_y = y as Coord + offset.y();
+                if next_x < 0 || next_y < 0 || next_x as usize >= width || next_y as usize >= height
+                {
+                    continue;
+                }
+
+                let index = (next_y as usize * width) + next_x as usize;
+                let position = *area.position() + Position::new(next_x, next_y);
+                if distances[index] == 0 && mask.intersects_position(position) {
+                    distances[index] = distance + 1;
+                    queue.push_back((next_x as usize, next_y as usize));
+                }
+            }
+        }
+
+        Self::from_tiles(area, noise, threshold, falloff, |position| {
+            let local = position - *area.position();
+            let distance = distances[(local.y() as usize * width) + local.x() as usize];
+            if distance <= 1 {
+                0.0
+            } else if falloff == 0 {
+                1.0
+            } else {
+                ((distance - 1) as f64 / falloff as f64).min(1.0)
+            }
+        })
+    }
+
+    /// Returns the number of tiles inward from the edge of the falloff mask over which the noise fades; zero if there is no mask.
+    pub fn falloff(&self) -> Length {
+        self.falloff
+    }
+
+    /// Returns the `GradientNoise` the shape was made from.
+    pub fn noise(&self) -> GradientNoise {
+        self.noise
+    }
+
+    /// Returns the value the noise must reach for a tile to be part of the shape.
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    /// Rasterizes the shape, where the fade of each tile is between `0.0`, where the noise is `-1.0` and the tile is never kept, and `1.0`, where the noise is unchanged.
+    fn from_tiles<TFade>(
+        area: Area,
+        noise: GradientNoise,
+        threshold: f64,
+        falloff: Length,
+        fade: TFade,
+    ) -> Self
+    where
+        TFade: Fn(Position) -> f64,
+    {
+        let mut bitmask = BitmaskShape::new(area);
+        for y in 0..area.height() as Coord {
+            for x in 0..area.width() as Coord {
+                let position = *area.position() + Position::new(x, y);
+                let fade = fade(position);
+                if fade <= 0.0 {
+                    continue;
+                }
+
+                let value = noise.value_at(position.x() as f64, position.y() as f64);
+                if -1.0 + ((value + 1.0) * fade) >= threshold {
+                    bitmask.set_containment(position, Containment::Intersects);
+                }
+            }
+        }
+        bitmask.recalculate_containment();
+
+        Self {
+            bitmask,
+            noise,
+            threshold,
+            falloff,
+        }
+    }
+}
+
+impl ContainsLocalPosition for NoiseShape {
+    fn contains_local_position(&self, position: Position) -> Containment {
+        self.bitmask.contains_local_position(position)
+    }
+}
+
+impl ContainsPosition for NoiseShape {}
+
+impl CountsTiles for NoiseShape {
+    fn count_tiles(&self) -> TileCount {
+        self.bitmask.count_tiles()
+    }
+}
+
+impl HasArea for NoiseShape {
+    fn area(&self) -> &Area {
+        self.bitmask.area()
+    }
+
+    fn area_mut(&mut self) -> &mut Area {
+        self.bitmask.area_mut()
+    }
+}
+
+impl HasPosition for NoiseShape {
+    fn position(&self) -> &Position {
+        self.bitmask.position()
+    }
+
+    fn position_mut(&mut self) -> &mut Position {
+        self.bitmask.position_mut()
+    }
+}
+
+impl HasSize for NoiseShape {
+    fn size(&self) -> &Size {
+        self.bitmask.size()
+    }
+
+    fn size_mut(&mut self) -> &mut Size {
+        self.bitmask.size_mut()
+    }
+}
+
+impl IntersectsLocalPosition for NoiseShape {
+    fn intersects_local_position(&self, position: Position) -> bool {
+        self.bitmask.intersects_local_position(position)
+    }
+}
+
+impl IntersectsPlacedShape for NoiseShape {}
+
+impl IntersectsPosition for NoiseShape {}
+
+impl IntersectsShape for NoiseShape {
+    fn intersects_shape(&self, other_offset: &Position, other_shape: &dyn Shape) -> bool {
+        self.bitmask.intersects_shape(other_offset, other_shape)
+    }
+}
+
+impl IsArea for NoiseShape {}
+
+impl IsSize for NoiseShape {}
+
+impl Placed for NoiseShape {}
+
+impl PlacedObject for NoiseShape {}
+
+impl ProvidesArea for NoiseShape {
+    fn provide_area(&self) -> Area {
+        *self.area()
+    }
+}
+
+impl ProvidesPlacedShape for NoiseShape {
+    fn provide_placed_shape(&self) -> Box<dyn PlacedShape> {
+        Box::new(self.clone())
+    }
+}
+
+impl ProvidesSize for NoiseShape {
+    fn provide_size(&self) -> Size {
+        *self.size()
+    }
+}
+
+impl Shape for NoiseShape {
+    fn box_shape_clone(&self) -> Box<dyn Shape> {
+        Box::new((*self).clone())
+    }
+}
@@ -3,11 +3,15 @@
 // Standard includes.
 
 // Internal includes.
+use super::containment::containment_from_neighbours;
+use super::intersects_placed_shape::placed_shape_bounds;
+use super::intersects_shape::placed_shape_area;
 use super::{
-    Area, Containment, ContainsLocalPosition, ContainsPosition, HasArea, HasHeight, HasPosition,
-    HasSize, HasWidth, IntersectsLocalPosition, IntersectsPosition, IsPosition, Length, Placed,
-    PlacedObject, PlacedShape, Position, ProvidesArea, ProvidesPlacedShape, ProvidesSize, Shape,
-    Size,
+    Area, Containment, ContainsLocalPosition, ContainsPosition, Coord, CountsTiles, HasArea,
+    HasBottom, HasHeight, HasLeft, HasPosition, HasRight, HasSize, HasTop, HasWidth,
+    IntersectsLocalPosition, IntersectsPlacedShape, IntersectsPosition, IntersectsShape,
+    IsPosition, Length, Placed, PlacedObject, PlacedShape, Position, ProvidesArea,
+    ProvidesPlacedShape, ProvidesSize, Shape, Size, TileCount,
 };
 
 /// Defines an oval by a [`Position`](struct.Position.html) and [`Size`](struct.Size.html).
@@ -15,7 +19,7 @@ use super::{
 ///
 /// The `Position` of the oval is the top-left corner of the rectangle surrounding the oval, and the `Size` of the oval determines the bottom-right corner of the rectangle surrounding the oval.
 ///
-/// The oval is calculated from these extents.
+/// The oval is calculated from these extents. A tile intersects the oval if its center lies within the ellipse which touches the edges of the surrounding rectangle, or if it is in the middle row or column of the rectangle; the middle row and column are kept so that the oval fills its rectangle however thin it is. The calculation uses only integers, so every oval is exactly symmetric, and a tile is contained only if all eight of its neighbours intersect.
 /// ```
 /// # use dungen_minion_geometry::*;
 ///
@@ -142,6 +146,92 @@ use super::{
 ///     test_oval(oval);
 /// });
 /// ```
+///
+/// Every `Size`, including those zero, one, or two tiles wide or tall, gives a symmetric oval with unbroken rows and columns.
+/// ```
+/// # use dungen_minion_geometry::*;
+/// use rayon::prelude::*;
+///
+/// // Checks one oval, and returns the left-most and right-most local x-coordinates of each of its rows.
+/// fn check_oval(width: Length, height: Length) -> Vec<(Coord, Coord)> {
+///     let oval = Oval::new(Position::new(0, 0), Size::new(width, height));
+///     let (w, h) = (width as Coord, height as Coord);
+///     let mut spans = Vec::new();
+///     for y in -1..=h {
+///         let mut span: Option<(Coord, Coord)> = None;
+///         for x in -1..=w {
+///             if oval.intersects_local_position(Position::new(x, y)) {
+///                 // Only tiles inside of the bounding rectangle intersect, and each row is unbroken.
+///                 assert!(x >= 0 && y >= 0 && x < w && y < h);
+///                 span = match span {
+///                     Some((left, right)) if right == x - 1 => Some((left, x)),
+///                     Some(_) => panic!("The row is broken."),
+///                     None => Some((x, x)),
+///                 };
+///             }
+///         }
+///         if y >= 0 && y < h && w > 0 {
+///             // Every row of the bounding rectangle is used.
+///             spans.push(span.unwrap());
+///         }
+///     }
+///
+///     let mut count = TileCount::default();
+///     for y in 0..spans.len() {
+///         let (left, right) = spans[y];
+///         // Mirror-symmetric on both axes.
+///         assert!(left == w - 1 - right);
+///         assert!(spans[y] == spans[spans.len() - 1 - y]);
+///         // Columns are unbroken if rows only widen towards the middle.
+///         if y > 0 && 2 * y < spans.len() {
+///             assert!(left <= spans[y - 1].0);
+///         }
+///
+///         // A tile is contained if all eight of its neighbours intersect.
+///         let (interior_left, interior_right) = if y > 0 && y + 1 < spans.len() {
+///             let (above_left, above_right) = spans[y - 1];
+///             let (below_left, below_right) = spans[y + 1];
+///             (left.max(above_left).max(below_left) + 1, right.min(above_right).min(below_right) - 1)
+///         } else {
+///             (0, -1)
+///         };
+///         let interior = (interior_right - interior_left + 1).max(0) as usize;
+///         count += TileCount::new(interior, (right - left + 1) as usize - interior);
+///         // Checking the `Containment` of every tile directly is slow, so only smaller ovals are checked tile by tile.
+///         if width <= 32 && height <= 32 {
+///             for x in -1..=w {
+///                 let expected = if x < left || x > right {
+///                     Containment::Disjoint
+///                 } else if x >= interior_left && x <= interior_right {
+///                     Containment::Contains
+///                 } else {
+///                     Containment::Intersects
+///                 };
+///                 assert!(oval.contains_local_position(Position::new(x, y as Coord)) == expected);
+///             }
+///         }
+///     }
+///     assert!(oval.count_tiles() == count);
+///
+///     spans
+/// }
+///
+/// (0..=128).into_par_iter().for_each(|width: Length| {
+///     for height in width..=128 {
+///         let spans = check_oval(width, height);
+///         let transposed_spans = check_oval(height, width);
+///         // Each column of the oval is a row of the transposed oval.
+///         for x in 0..transposed_spans.len() {
+///             let x = x as Coord;
+///             let top = spans
+///                 .iter()
+///                 .position(|(left, right)| *left <= x && x <= *right)
+///                 .unwrap() as Coord;
+///             assert!(transposed_spans[x as usize] == (top, height as Coord - 1 - top));
+///         }
+///     }
+/// });
+/// ```
 #[derive(Copy, Clone, Debug, Display)]
 pub struct Oval {
     area: Area,
@@ -156,52 +246,80 @@ impl Oval {
             area: Area::new(position, size),
         }
     }
-}
 
-impl ContainsLocalPosition for Oval {
-    fn contains_local_position(&self, position: Position) -> Containment {
-        if !self.intersects_local_position(position) {
-            return Containment::Disjoint;
+    /// Returns the left-most and right-most local x-coordinates of the given local row, or `None` if the row does not intersect the oval.
+    fn local_row_span(&self, y: Coord) -> Option<(Coord, Coord)> {
+        if self.width() == 0 || y < 0 || y as Length >= self.height() {
+            return None;
         }
 
-        let fwidth = ((self.area.width() as f64 / 2.0) - 0.5).max(0.0);
-        let fheight = ((self.area.height() as f64 / 2.0) - 0.5).max(0.0);
-        let fmin_bounds = (fwidth.min(fheight) - 1.0).max(0.0);
-        let fmin_bounds = (fmin_bounds * fmin_bounds) / 2.0;
-        let fmin_bounds = if fmin_bounds > 0.0 {
-            fmin_bounds.sqrt()
-        } else {
-            fmin_bounds
-        };
-        let flocal_center_x = fwidth;
-        let flocal_center_y = fheight;
-        let adjusted_position_x = position.x() as f64 - flocal_center_x;
-        let adjusted_position_y = position.y() as f64 - flocal_center_y;
-
-        if adjusted_position_x.abs() <= fmin_bounds
-            && adjusted_position_y.abs() <= fmin_bounds
-            && ((fmin_bounds - adjusted_position_x.abs() >= 1.0)
-                || (fmin_bounds - adjusted_position_y.abs() >= 1.0))
-        {
-            Containment::Contains
-        } else if !(self.intersects_local_position(position + Position::NORTH)
-            && self.intersects_local_position(position + Position::NORTH + Position::EAST)
-            && self.intersects_local_position(position + Position::EAST)
-            && self.intersects_local_position(position + Position::SOUTH + Position::EAST)
-            && self.intersects_local_position(position + Position::SOUTH)
-            && self.intersects_local_position(position + Position::SOUTH + Position::WEST)
-            && self.intersects_local_position(position + Position::WEST)
-            && self.intersects_local_position(position + Position::NORTH + Position::WEST))
-        {
-            Containment::Intersects
-        } else {
-            Containment::Contains
+        let center_x = (self.width() as Coord - 1) / 2;
+        if !self.intersects_local_position(Position::new(center_x, y)) {
+            return None;
+        }
+
+        // Each row of an oval is contiguous, so search outwards from the center.
+        let (mut low, mut high) = (0, center_x);
+        while low < high {
+            let middle = (low + high) / 2;
+            if self.intersects_local_position(Position::new(middle, y)) {
+                high = middle;
+            } else {
+                low = middle + 1;
+            }
+        }
+        let left = low;
+
+        let (mut low, mut high) = (center_x, self.width() as Coord - 1);
+        while low < high {
+            let middle = (low + high + 1) / 2;
+            if self.intersects_local_position(Position::new(middle, y)) {
+                low = middle;
+            } else {
+                high = middle - 1;
+            }
         }
+
+        Some((left, low))
+    }
+}
+
+impl ContainsLocalPosition for Oval {
+    fn contains_local_position(&self, position: Position) -> Containment {
+        containment_from_neighbours(position, |position| {
+            self.intersects_local_position(position)
+        })
     }
 }
 
 impl ContainsPosition for Oval {}
 
+impl CountsTiles for Oval {
+    fn count_tiles(&self) -> TileCount {
+        // Each row of an oval is contiguous, so the interior of a row is where it and the rows above and below it all span a tile's neighbours.
+        let spans: Vec<Option<(Coord, Coord)>> = (-1..=self.height() as Coord)
+            .map(|y| self.local_row_span(y))
+            .collect();
+        let mut count = TileCount::default();
+        for rows in spans.windows(3) {
+            if let Some((left, right)) = rows[1] {
+                let total = (right - left + 1) as usize;
+                let interior = match (rows[0], rows[2]) {
+                    (Some((above_left, above_right)), Some((below_left, below_right))) => {
+                        let interior_left = left.max(above_left).max(below_left) + 1;
+                        let interior_right = right.min(above_right).min(below_right) - 1;
+                        (interior_right - interior_left + 1).max(0) as usize
+                    }
+                    _ => 0,
+                };
+                count += TileCount::new(interior, total - interior);
+            }
+        }
+
+        count
+    }
+}
+
 impl HasArea for Oval {
     fn area(&self) -> &Area {
         &self.area
@@ -254,26 +372,84 @@ impl HasWidth for Oval {
 
 impl IntersectsLocalPosition for Oval {
     fn intersects_local_position(&self, position: Position) -> bool {
-        let fwidth = ((self.area.width() as f64 / 2.0) - 0.5).max(0.0);
-        let fheight = ((self.area.height() as f64 / 2.0) - 0.5).max(0.0);
-        let ratio: f64 = fwidth / fheight;
-        let flocal_center_x = fwidth;
-        let flocal_center_y = fheight;
-        let adjusted_position_x = position.x() as f64 - flocal_center_x;
-        let adjusted_position_y = position.y() as f64 - flocal_center_y;
-
-        let circular_position_x = adjusted_position_x / ratio;
-        let circular_position_y = adjusted_position_y;
-
-        let radius_sqr = fheight * fheight;
-        let dist_sqr = (circular_position_x * circular_position_x)
-            + (circular_position_y * circular_position_y);
-        radius_sqr >= dist_sqr
+        let width = self.width() as i64;
+        let height = self.height() as i64;
+        let x = position.x() as i64;
+        let y = position.y() as i64;
+        if x < 0 || y < 0 || x >= width || y >= height {
+            return false;
+        }
+
+        // Offsets from the center are doubled, so that they are whole numbers for both odd and even sizes.
+        let offset_x = ((2 * x) - (width - 1)).unsigned_abs();
+        let offset_y = ((2 * y) - (height - 1)).unsigned_abs();
+
+        // The middle row and column are always kept, so that the oval fills its bounding rectangle however thin it is.
+        if offset_x <= (width as u64 - 1) % 2 || offset_y <= (height as u64 - 1) % 2 {
+            return true;
+        }
+
+        // Otherwise, the center of the tile must be inside the ellipse touching the edges of the bounding rectangle.
+        let (width, height) = (width as u128, height as u128);
+        let (offset_x, offset_y) = (offset_x as u128, offset_y as u128);
+        (offset_x * offset_x * height * height) + (offset_y * offset_y * width * width)
+            <= width * width * height * height
+    }
+}
+
+impl IntersectsPlacedShape for Oval {
+    fn intersects_placed_shape(&self, placed_shape: &dyn PlacedShape) -> bool {
+        let overlap = match placed_shape_bounds(&self.area, placed_shape) {
+            Some(overlap) => overlap,
+            None => return false,
+        };
+
+        // Only the span of each row which lies inside the oval needs testing.
+        for y in overlap.top()..=overlap.bottom() {
+            if let Some((left, right)) = self.local_row_span(y - self.top()) {
+                let left = (self.left() + left).max(overlap.left());
+                let right = (self.left() + right).min(overlap.right());
+                for x in left..=right {
+                    if placed_shape.intersects_position(Position::new(x, y)) {
+                        return true;
+                    }
+                }
+            }
+        }
+
+        false
     }
 }
 
 impl IntersectsPosition for Oval {}
 
+impl IntersectsShape for Oval {
+    fn intersects_shape(&self, other_offset: &Position, other_shape: &dyn Shape) -> bool {
+        let overlap = match self
+            .area
+            .intersection(&placed_shape_area(other_offset, other_shape))
+        {
+            Some(overlap) => overlap,
+            None => return false,
+        };
+
+        // Only the span of each row which lies inside the oval needs testing.
+        for y in overlap.top()..=overlap.bottom() {
+            if let Some((left, right)) = self.local_row_span(y - self.top()) {
+                let left = (self.left() + left).max(overlap.left());
+                let right = (self.left() + right).min(overlap.right());
+                for x in left..=right {
+                    if other_shape.intersects_local_position(Position::new(x, y) - *other_offset) {
+                        return true;
+                    }
+                }
+            }
+        }
+
+        false
+    }
+}
+
 impl Placed for Oval {}
 
 impl PlacedObject for Oval {}
@@ -0,0 +1,37 @@
+// External includes.
+
+// Standard includes.
+use std::error::Error;
+use std::fmt;
+
+// Internal includes.
+use super::Position;
+
+/// The error returned when ASCII art cannot be parsed into a shape.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ParseAsciiError {
+    /// A character which is not part of the [`AsciiPalette`](struct.AsciiPalette.html) was found at the given local `Position`.
+    UnknownCharacter {
+        /// The character which was found.
+        character: char,
+        /// The local `Position` of the character; x is the column, and y is the line.
+        position: Position,
+    },
+}
+
+impl fmt::Display for ParseAsciiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseAsciiError::UnknownCharacter {
+                character,
+                position,
+            } => write!(
+                f,
+                "Unknown character {:?} at local position {}.",
+                character, position
+            ),
+        }
+    }
+}
+
+impl Error for ParseAsciiError {}
@@ -0,0 +1,294 @@
+// External includes.
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+// Standard includes.
+use std::f64::consts::PI;
+
+// Internal includes.
+use super::cave_generator::connected_regions;
+use super::{
+    Area, BitmaskShape, Connectivity, Containment, ContainsLocalPosition, ContainsPosition, Coord,
+    CountsTiles, HasArea, HasHeight, HasPosition, HasSize, HasWidth, IntersectsLocalPosition,
+    IntersectsPlacedShape, IntersectsPosition, IntersectsShape, IsArea, IsPosition, IsSize, Length,
+    Oval, Placed, PlacedObject, PlacedShape, Position, ProvidesArea, ProvidesPlacedShape,
+    ProvidesSize, Shape, Size, TileCount,
+};
+
+/// Defines an organic, blobby chamber by randomly perturbing the radius of an [`Oval`](struct.Oval.html) around its perimeter.
+///
+/// Random radii are placed evenly around the perimeter, as far apart as the smoothness, in tiles, and the radius between them eases from one to the next. Each radius is shrunk from the radius of the oval by up to the amplitude, as a fraction between `0.0` and `1.0`; a tile is kept if the oval intersects it and it is within the perturbed radius.
+///
+/// Only the tiles which share sides with a path to the center tile are kept, so the chamber is always in one piece, and a tile is contained only if all eight of its neighbours are kept, so its walls are always closed.
+///
+/// The chamber is rasterized once, when it is created, and its [`Area`](struct.Area.html) is the `Area` of the oval.
+/// ```
+/// # use dungen_minion_geometry::*;
+/// use std::collections::{HashSet, VecDeque};
+///
+/// fn test_chamber(chamber: &PerturbedOval) {
+///     // Perform a flood-fill from the center to ensure the chamber is bounded.
+///     let mut queue = VecDeque::new();
+///     let mut visited = HashSet::new();
+///     let center = Position::new(
+///         (chamber.width() as Coord - 1) / 2,
+///         (chamber.height() as Coord - 1) / 2,
+///     );
+///     if chamber.contains_local_position(center) == Containment::Contains {
+///         queue.push_back(center);
+///     }
+///     while let Some(current_position) = queue.pop_front() {
+///         if !visited.insert(current_position) {
+///             continue;
+///         }
+///
+///         for y in -1..=1 {
+///             for x in -1..=1 {
+///                 let test_position = current_position + Position::new(x, y);
+///                 match chamber.contains_local_position(test_position) {
+///                     // We have escaped the chamber. The perimeter is not closed!
+///                     Containment::Disjoint => panic!("Chamber perimeter is not closed!"),
+///                     Containment::Intersects => continue,
+///                     Containment::Contains => {
+///                         if !visited.contains(&test_position) {
+///                             queue.push_back(test_position);
+///                         }
+///                     }
+///                 }
+///             }
+///         }
+///     }
+///
+///     // The chamber is in one piece; every tile can be reached from the center.
+///     let tiles: HashSet<Position> = chamber.local_tiles().map(|(position, _)| position).collect();
+///     let mut reached = HashSet::new();
+///     let mut stack = vec![center];
+///     while let Some(position) = stack.pop() {
+///         if tiles.contains(&position) && reached.insert(position) {
+///             stack.extend(Connectivity::Four.offsets().iter().map(|offset| position + *offset));
+///         }
+///     }
+///     assert!(reached == tiles);
+///
+///     // Every tile is inside of the oval.
+///     let oval = chamber.oval();
+///     assert!(chamber.tiles().all(|(position, _)| oval.intersects_position(position)));
+/// }
+///
+/// let oval = Oval::new(Position::new(-10, -6), Size::new(21, 13));
+/// let chamber = PerturbedOval::new(oval, 42, 0.4, 4);
+/// assert!(*chamber.area() == *oval.area());
+/// assert!(chamber.contains_position(Position::new(0, 0)) == Containment::Contains);
+/// assert!(chamber.count_tiles().total() < oval.count_tiles().total());
+/// test_chamber(&chamber);
+///
+/// // The same seed always gives the same chamber.
+/// assert!(chamber.tiles().eq(PerturbedOval::new(oval, 42, 0.4, 4).tiles()));
+/// assert!(!chamber.tiles().eq(PerturbedOval::new(oval, 43, 0.4, 4).tiles()));
+///
+/// // Without an amplitude, the chamber is the oval.
+/// assert!(PerturbedOval::new(oval, 42, 0.0, 4).tiles().eq(oval.tiles()));
+///
+/// // The oval moves with the chamber.
+/// let mut moved = chamber.clone();
+/// *moved.position_mut() = Position::new(50, 50);
+/// assert!(*moved.oval().area() == Area::new(Position::new(50, 50), Size::new(21, 13)));
+/// test_chamber(&moved);
+///
+/// for seed in 0..20 {
+///     for (width, height) in [(5, 5), (8, 6), (15, 9), (24, 24), (40, 13)].iter() {
+///         for amplitude in [0.25, 0.6, 1.0].iter() {
+///             let oval = Oval::new(Position::new(seed as Coord, 0), Size::new(*width, *height));
+///             test_chamber(&PerturbedOval::new(oval, seed, *amplitude, 1 + (seed as Length % 6)));
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct PerturbedOval {
+    bitmask: BitmaskShape,
+    seed: u64,
+    amplitude: f64,
+    smoothness: Length,
+}
+
+impl PerturbedOval {
+    /// Creates a new `PerturbedOval` from an `Oval`, the seed of its random radii, the amplitude they are shrunk by, and the smoothness, in tiles around the perimeter between them.
+    ///
+    /// The same seed always gives the same chamber, on every platform. The amplitude is clamped to between `0.0` and `1.0`, and a smoothness of zero is treated as one.
+    pub fn new(oval: Oval, seed: u64, amplitude: f64, smoothness: Length) -> Self {
+        let amplitude = amplitude.clamp(0.0, 1.0);
+        let area = *oval.area();
+        let width = area.width() as usize;
+        let height = area.height() as usize;
+
+        // Place random radii evenly around the perimeter.
+        let perimeter = PI * (width + height) as f64 / 2.0;
+        let radius_count = ((perimeter / smoothness.max(1) as f64).round() as usize).max(3);
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let radii: Vec<f64> = (0..radius_count)
+            .map(|_| 1.0 - (amplitude * rng.gen::<f64>()))
+            .collect();
+
+        let mut is_set = vec![false; width * height];
+        for y in 0..height {
+            for x in 0..width {
+                let position = Position::new(x as Coord, y as Coord);
+                if !oval.intersects_local_position(position) {
+                    continue;
+                }
+
+                // Offsets from the center are scaled, so that the edge of the oval is at a distance of one.
+                let offset_x = ((2 * x) as f64 - (width - 1) as f64) / width as f64;
+                let offset_y = ((2 * y) as f64 - (height - 1) as f64) / height as f64;
+                let distance = ((offset_x * offset_x) + (offset_y * offset_y)).sqrt();
+                let turn = offset_y.atan2(offset_x).rem_euclid(2.0 * PI) / (2.0 * PI);
+                is_set[(y * width) + x] = amplitude == 0.0 || distance <= radius_at(&radii, turn);
+            }
+        }
+
+        let mut bitmask = BitmaskShape::new(area);
+        if width > 0 && height > 0 {
+            let center = Position::new(((width - 1) / 2) as Coord, ((height - 1) / 2) as Coord);
+            is_set[(center.y() as usize * width) + center.x() as usize] = true;
+            // Keep only the region holding the center, so that the chamber is in one piece.
+            let regions = connected_regions(width, height, &is_set, Connectivity::Four);
+            let region = regions
+                .iter()
+                .find(|region| region.contains(&center))
+                .unwrap();
+            for position in region.iter() {
+                bitmask.set_local_containment(*position, Containment::Intersects);
+            }
+        }
+        bitmask.recalculate_containment();
+
+        Self {
+            bitmask,
+            seed,
+            amplitude,
+            smoothness,
+        }
+    }
+
+    /// Returns the largest fraction of the radius of the oval which the random radii are shrunk by.
+    pub fn amplitude(&self) -> f64 {
+        self.amplitude
+    }
+
+    /// Returns the `Oval` the chamber was perturbed from, in the chamber's current `Area`.
+    pub fn oval(&self) -> Oval {
+        Oval::new(*self.position(), *self.size())
+    }
+
+    /// Returns the seed of the random radii.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns the distance around the perimeter, in tiles, between random radii.
+    pub fn smoothness(&self) -> Length {
+        self.smoothness
+    }
+}
+
+/// Returns the radius at the given fraction of a turn around the perimeter, easing between the random radii on either side of it.
+fn radius_at(radii: &[f64], turn: f64) -> f64 {
+    let scaled = turn * radii.len() as f64;
+    let index = (scaled.floor() as usize) % radii.len();
+    let t = scaled - scaled.floor();
+    let t = t * t * (3.0 - (2.0 * t));
+    radii[index] + ((radii[(index + 1) % radii.len()] - radii[index]) * t)
+}
+
+impl ContainsLocalPosition for PerturbedOval {
+    fn contains_local_position(&self, position: Position) -> Containment {
+        self.bitmask.contains_local_position(position)
+    }
+}
+
+impl ContainsPosition for PerturbedOval {}
+
+impl CountsTiles for PerturbedOval {
+    fn count_tiles(&self) -> TileCount {
+        self.bitmask.count_tiles()
+    }
+}
+
+impl HasArea for PerturbedOval {
+    fn area(&self) -> &Area {
+        self.bitmask.area()
+    }
+
+    fn area_mut(&mut self) -> &mut Area {
+        self.bitmask.area_mut()
+    }
+}
+
+impl HasPosition for PerturbedOval {
+    fn position(&self) -> &Position {
+        self.bitmask.position()
+    }
+
+    fn position_mut(&mut self) -> &mut Position {
+        self.bitmask.position_mut()
+    }
+}
+
+impl HasSize for PerturbedOval {
+    fn size(&self) -> &Size {
+        self.bitmask.size()
+    }
+
+    fn size_mut(&mut self) -> &mut Size {
+        self.bitmask.size_mut()
+    }
+}
+
+impl IntersectsLocalPosition for PerturbedOval {
+    fn intersects_local_position(&self, position: Position) -> bool {
+        self.bitmask.intersects_local_position(position)
+    }
+}
+
+impl IntersectsPlacedShape for PerturbedOval {}
+
+impl IntersectsPosition for PerturbedOval {}
+
+impl IntersectsShape for PerturbedOval {
+    fn intersects_shape(&self, other_offset: &Position, other_shape: &dyn Shape) -> bool {
+        self.bitmask.intersects_shape(other_offset, other_shape)
+    }
+}
+
+impl IsArea for PerturbedOval {}
+
+impl IsSize for PerturbedOval {}
+
+impl Placed for PerturbedOval {}
+
+impl PlacedObject for PerturbedOval {}
+
+impl ProvidesArea for PerturbedOval {
+    fn provide_area(&self) -> Area {
+        *self.area()
+    }
+}
+
+impl ProvidesPlacedShape for PerturbedOval {
+    fn provide_placed_shape(&self) -> Box<dyn PlacedShape> {
+        Box::new(self.clone())
+    }
+}
+
+impl ProvidesSize for PerturbedOval {
+    fn provide_size(&self) -> Size {
+        *self.size()
+    }
+}
+
+impl Shape for PerturbedOval {
+    fn box_shape_clone(&self) -> Box<dyn Shape> {
+        Box::new((*self).clone())
+    }
+}
@@ -4,7 +4,8 @@
 
 // Internal includes.
 use super::{
-    ContainsPosition, HasBottom, HasLeft, HasRight, HasTop, IntersectsPosition, PlacedObject, Shape,
+    ContainsPosition, CountsTiles, HasBottom, HasLeft, HasRight, HasTop, IntersectsPlacedShape,
+    IntersectsPosition, PlacedObject, Shape,
 };
 
 /// Defines a 2D tile-based shape with a [`Position`](struct.Position.html).
@@ -12,10 +13,12 @@ use super::{
 /// "shape" is used instead of "polygon" as shapes do not necessarily have regular vertices or edges, nor do they necessarily conform to any regular geometric definition.
 pub trait PlacedShape:
     ContainsPosition
+    + CountsTiles
     + HasBottom
     + HasLeft
     + HasRight
     + HasTop
+    + IntersectsPlacedShape
     + IntersectsPosition
     + PlacedObject
     + Shape
@@ -37,7 +40,13 @@ impl Clone for Box<dyn PlacedShape> {
 
 impl<TShape: 'static> PlacedShape for TShape
 where
-    TShape: Clone + ContainsPosition + IntersectsPosition + PlacedObject + Shape,
+    TShape: Clone
+        + ContainsPosition
+        + CountsTiles
+        + IntersectsPlacedShape
+        + IntersectsPosition
+        + PlacedObject
+        + Shape,
 {
     fn box_placed_shape_clone(&self) -> Box<dyn PlacedShape> {
         Box::new((*self).clone())
@@ -3,11 +3,13 @@
 // Standard includes.
 
 // Internal includes.
+use super::intersects_shape::rasterized_intersects_shape;
 use super::{
-    Area, Containment, ContainsLocalPosition, ContainsPosition, Coord, HasArea, HasBottom,
-    HasPosition, HasRight, HasSize, Inclusion, IntersectsLocalPosition, IntersectsPosition, IsArea,
-    IsSize, Placed, PlacedObject, PlacedShape, Position, ProvidesArea, ProvidesPlacedShape,
-    ProvidesSize, Shape, Size,
+    Area, Containment, ContainsLocalPosition, ContainsPosition, Coord, CountsTiles, HasArea,
+    HasBottom, HasPosition, HasRight, HasSize, Inclusion, IntersectsLocalPosition,
+    IntersectsPlacedShape, IntersectsPosition, IntersectsShape, IsArea, IsSize, Placed,
+    PlacedObject, PlacedShape, Position, ProvidesArea, ProvidesPlacedShape, ProvidesSize, Shape,
+    Size,
 };
 
 /// Contains a slice of [`PlacedShape`](trait.PlacedShape.html) and implements `PlacedShape` for the collection.
@@ -96,6 +98,8 @@ impl ContainsPosition for PlacedShapeSlice {
     }
 }
 
+impl CountsTiles for PlacedShapeSlice {}
+
 impl HasArea for PlacedShapeSlice {
     fn area(&self) -> &Area {
         &self.area
@@ -138,6 +142,19 @@ impl IntersectsPosition for PlacedShapeSlice {
     }
 }
 
+impl IntersectsPlacedShape for PlacedShapeSlice {}
+
+impl IntersectsShape for PlacedShapeSlice {
+    fn intersects_shape(&self, other_offset: &Position, other_shape: &dyn Shape) -> bool {
+        rasterized_intersects_shape(
+            self.area(),
+            |position| self.intersects_position(position),
+            other_offset,
+            other_shape,
+        )
+    }
+}
+
 impl IsArea for PlacedShapeSlice {}
 
 impl IsSize for PlacedShapeSlice {}
@@ -0,0 +1,370 @@
+// External includes.
+
+// Standard includes.
+
+// Internal includes.
+use super::line_shape::line_positions;
+use super::{
+    Area, BitmaskShape, Containment, ContainsLocalPosition, ContainsPosition, Coord, CountsTiles,
+    FillRule, HasArea, HasBottom, HasHeight, HasPosition, HasRight, HasSize, HasWidth,
+    IntersectsLocalPosition, IntersectsPlacedShape, IntersectsPosition, IntersectsShape, IsArea,
+    IsPosition, IsSize, Length, Placed, PlacedObject, PlacedShape, Position, ProvidesArea,
+    ProvidesPlacedShape, ProvidesSize, Shape, Size, TileCount,
+};
+
+/// Defines a polygon by a list of vertex [`Position`](struct.Position.html)s, which may be convex or concave.
+///
+/// The last vertex is joined back to the first. A tile is inside the polygon if its center is inside, as decided by the [`FillRule`](enum.FillRule.html), or if a one-tile line drawn along an edge passes through it. The tiles along the edges always report [`Containment::Intersects`](enum.Containment.html), as do tiles next to the outside of the polygon; every other tile inside the polygon reports `Containment::Contains`. The bounding [`Area`](struct.Area.html) is the smallest `Area` containing every vertex.
+///
+/// The polygon is rasterized once, when it is created.
+/// ```
+/// # use dungen_minion_geometry::*;
+/// let triangle = PolygonShape::new(
+///     &[Position::new(0, 0), Position::new(6, 0), Position::new(0, 6)],
+///     FillRule::EvenOdd,
+/// );
+/// assert!(*triangle.area() == Area::new(Position::new(0, 0), Size::new(7, 7)));
+/// assert!(
+///     triangle.render_ascii(&AsciiPalette::default())
+///         == concat!(
+///             "#######\n",
+///             "#...## \n",
+///             "#..##  \n",
+///             "#.##   \n",
+///             "###    \n",
+///             "##     \n",
+///             "#      \n",
+///         )
+/// );
+///
+/// // Concave polygons leave out what is outside of them.
+/// let cavern = PolygonShape::new(
+///     &[
+///         Position::new(0, 0),
+///         Position::new(8, 0),
+///         Position::new(8, 3),
+///         Position::new(3, 3),
+///         Position::new(3, 8),
+///         Position::new(0, 8),
+///     ],
+///     FillRule::EvenOdd,
+/// );
+/// assert!(cavern.contains_position(Position::new(1, 6)) == Containment::Contains);
+/// assert!(cavern.contains_position(Position::new(3, 6)) == Containment::Intersects);
+/// assert!(cavern.contains_position(Position::new(6, 6)) == Containment::Disjoint);
+///
+/// // Where the edges cross, the fill rule decides what is inside.
+/// let star = [
+///     Position::new(10, 0),
+///     Position::new(16, 20),
+///     Position::new(0, 7),
+///     Position::new(20, 7),
+///     Position::new(4, 20),
+/// ];
+/// let even_odd = PolygonShape::new(&star, FillRule::EvenOdd);
+/// let non_zero = PolygonShape::new(&star, FillRule::NonZero);
+/// assert!(even_odd.contains_position(Position::new(10, 11)) == Containment::Disjoint);
+/// assert!(non_zero.contains_position(Position::new(10, 11)) == Containment::Contains);
+/// assert!(even_odd.contains_position(Position::new(10, 4)) == Containment::Contains);
+///
+/// // Moving the polygon moves its vertices.
+/// let mut triangle = triangle;
+/// *triangle.position_mut() = Position::new(10, 20);
+/// assert!(triangle.vertices()[1] == Position::new(16, 20));
+/// assert!(triangle.contains_position(Position::new(11, 21)) == Containment::Contains);
+/// ```
+#[derive(Clone, Debug)]
+pub struct PolygonShape {
+    bitmask: BitmaskShape,
+    local_vertices: Vec<Position>,
+    fill_rule: FillRule,
+}
+
+impl PolygonShape {
+    /// Creates a new `PolygonShape` from the given vertices, filled with the given `FillRule`.
+    ///
+    /// No vertices creates a polygon with no tiles.
+    pub fn new(vertices: &[Position], fill_rule: FillRule) -> Self {
+        let area = if vertices.is_empty() {
+            Area::new(Position::zero(), Size::zero())
+        } else {
+            let left = vertices.iter().map(|vertex| vertex.x()).min().unwrap();
+            let top = vertices.iter().map(|vertex| vertex.y()).min().unwrap();
+            let mut area = Area::new(Position::new(left, top), Size::zero());
+            area.right_set(vertices.iter().map(|vertex| vertex.x()).max().unwrap());
+            area.bottom_set(vertices.iter().map(|vertex| vertex.y()).max().unwrap());
+            area
+        };
+        let local_vertices: Vec<Position> = vertices
+            .iter()
+            .map(|vertex| *vertex - *area.position())
+            .collect();
+
+        let mut bitmask = BitmaskShape::new(area);
+        let edges: Vec<(Position, Position)> = (0..local_vertices.len())
+            .map(|i| {
+                (
+                    local_vertices[i],
+                    local_vertices[(i + 1) % local_vertices.len()],
+                )
+            })
+            .collect();
+
+        // Fill each row, by tile center, from the edges which cross it.
+        let mut crossings = Vec::new();
+        for y in 0..area.height() as Coord {
+            crossings.clear();
+            for (start, end) in edges.iter() {
+                let (low, high, winding) = if start.y() < end.y() {
+                    (*start, *end, 1)
+                } else {
+                    (*end, *start, -1)
+                };
+                if low.y() <= y && y < high.y() {
+                    let x = low.x() as f64
+                        + ((y - low.y()) as f64 * (high.x() - low.x()) as f64
+                            / (high.y() - low.y()) as f64);
+                    crossings.push((x, winding));
+                }
+            }
+            crossings.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+            let mut next_crossing = 0;
+            let mut count = 0;
+            let mut winding = 0;
+            for x in 0..area.width() as Coord {
+                while next_crossing < crossings.len() && crossings[next_crossing].0 < x as f64 {
+                    count += 1;
+                    winding += crossings[next_crossing].1;
+                    next_crossing += 1;
+                }
+                let is_inside = match fill_rule {
+                    FillRule::EvenOdd => count % 2 == 1,
+                    FillRule::NonZero => winding != 0,
+                };
+                if is_inside {
+                    bitmask.set_local_containment(Position::new(x, y), Containment::Intersects);
+                }
+            }
+        }
+
+        let edge_positions: Vec<Position> = edges
+            .iter()
+            .flat_map(|(start, end)| line_positions(*start, *end))
+            .collect();
+        for position in edge_positions.iter() {
+            bitmask.set_local_containment(*position, Containment::Intersects);
+        }
+        bitmask.recalculate_containment();
+        // The edges stay edges, even where the polygon overlaps itself around them.
+        for position in edge_positions.iter() {
+            bitmask.set_local_containment(*position, Containment::Intersects);
+        }
+
+        Self {
+            bitmask,
+            local_vertices,
+            fill_rule,
+        }
+    }
+
+    /// Creates a new regular `PolygonShape`, such as a hexagon or an octagon, with its vertices on a circle around the center.
+    ///
+    /// The first vertex is directly north of the center, turned clockwise by the rotation in degrees. Vertices are rounded to the nearest tile.
+    /// ```
+    /// # use dungen_minion_geometry::*;
+    /// // Turning an octagon by half of a side gives it flat sides to the north, east, south and west.
+    /// let octagon = PolygonShape::regular(Position::new(10, 10), 5, 8, 22.5);
+    /// assert!(*octagon.area() == Area::new(Position::new(5, 5), Size::new(11, 11)));
+    /// assert!(
+    ///     octagon.render_ascii(&AsciiPalette::default())
+    ///         == concat!(
+    ///             "   #####   \n",
+    ///             "  ##...##  \n",
+    ///             " ##.....## \n",
+    ///             "##.......##\n",
+    ///             "#.........#\n",
+    ///             "#.........#\n",
+    ///             "#.........#\n",
+    ///             "##.......##\n",
+    ///             " ##.....## \n",
+    ///             "  ##...##  \n",
+    ///             "   #####   \n",
+    ///         )
+    /// );
+    ///
+    /// // A hexagon with a vertex to the north.
+    /// let hexagon = PolygonShape::regular(Position::new(0, 0), 6, 6, 0.0);
+    /// assert!(hexagon.vertices()[0] == Position::new(0, -6));
+    /// assert!(hexagon.vertices()[3] == Position::new(0, 6));
+    /// assert!(*hexagon.area() == Area::new(Position::new(-5, -6), Size::new(11, 13)));
+    /// assert!(hexagon.contains_position(Position::new(0, 0)) == Containment::Contains);
+    /// ```
+    pub fn regular(
+        center: Position,
+        circumradius: Length,
+        vertex_count: usize,
+        rotation: f64,
+    ) -> Self {
+        let vertices: Vec<Position> = (0..vertex_count)
+            .map(|i| {
+                let angle = rotation + (i as f64 * 360.0 / vertex_count as f64);
+                vertex_at(center, circumradius as f64, angle)
+            })
+            .collect();
+        Self::new(&vertices, FillRule::EvenOdd)
+    }
+
+    /// Creates a new star-shaped `PolygonShape`, with points on a circle around the center and the vertices between them on a smaller circle.
+    ///
+    /// The inner radius is the circumradius scaled by the inner radius ratio. The first point is directly north of the center, turned clockwise by the rotation in degrees. Vertices are rounded to the nearest tile.
+    /// ```
+    /// # use dungen_minion_geometry::*;
+    /// let star = PolygonShape::star(Position::new(0, 0), 10, 5, 0.0, 0.4);
+    /// assert!(star.vertices().len() == 10);
+    /// assert!(star.vertices()[0] == Position::new(0, -10));
+    /// assert!(star.top() == -10);
+    /// assert!(star.left() == -10 && star.right() == 10);
+    /// assert!(star.contains_position(Position::new(0, 0)) == Containment::Contains);
+    /// // Each point is inside the star, and the gaps between them are not.
+    /// assert!(star.intersects_position(Position::new(0, -8)));
+    /// assert!(!star.intersects_position(Position::new(5, -8)));
+    /// ```
+    pub fn star(
+        center: Position,
+        circumradius: Length,
+        point_count: usize,
+        rotation: f64,
+        inner_radius_ratio: f64,
+    ) -> Self {
+        let outer_radius = circumradius as f64;
+        let inner_radius = outer_radius * inner_radius_ratio;
+        let vertices: Vec<Position> = (0..point_count * 2)
+            .map(|i| {
+                let angle = rotation + (i as f64 * 180.0 / point_count as f64);
+                let radius = if i % 2 == 0 {
+                    outer_radius
+                } else {
+                    inner_radius
+                };
+                vertex_at(center, radius, angle)
+            })
+            .collect();
+        Self::new(&vertices, FillRule::NonZero)
+    }
+
+    /// Returns the `FillRule` used to decide which tiles are inside the polygon.
+    pub fn fill_rule(&self) -> FillRule {
+        self.fill_rule
+    }
+
+    /// Returns the vertices of the polygon.
+    pub fn vertices(&self) -> Vec<Position> {
+        self.local_vertices
+            .iter()
+            .map(|vertex| *self.position() + *vertex)
+            .collect()
+    }
+}
+
+/// Returns the tile at the given distance from the center, at an angle in degrees clockwise from north.
+fn vertex_at(center: Position, radius: f64, angle: f64) -> Position {
+    let radians = angle.to_radians();
+    center
+        + Position::new(
+            (radius * radians.sin()).round() as Coord,
+            (-radius * radians.cos()).round() as Coord,
+        )
+}
+
+impl ContainsLocalPosition for PolygonShape {
+    fn contains_local_position(&self, position: Position) -> Containment {
+        self.bitmask.contains_local_position(position)
+    }
+}
+
+impl ContainsPosition for PolygonShape {}
+
+impl CountsTiles for PolygonShape {
+    fn count_tiles(&self) -> TileCount {
+        self.bitmask.count_tiles()
+    }
+}
+
+impl HasArea for PolygonShape {
+    fn area(&self) -> &Area {
+        self.bitmask.area()
+    }
+
+    fn area_mut(&mut self) -> &mut Area {
+        self.bitmask.area_mut()
+    }
+}
+
+impl HasPosition for PolygonShape {
+    fn position(&self) -> &Position {
+        self.bitmask.position()
+    }
+
+    fn position_mut(&mut self) -> &mut Position {
+        self.bitmask.position_mut()
+    }
+}
+
+impl HasSize for PolygonShape {
+    fn size(&self) -> &Size {
+        self.bitmask.size()
+    }
+
+    fn size_mut(&mut self) -> &mut Size {
+        self.bitmask.size_mut()
+    }
+}
+
+impl IntersectsLocalPosition for PolygonShape {
+    fn intersects_local_position(&self, position: Position) -> bool {
+        self.bitmask.intersects_local_position(position)
+    }
+}
+
+impl IntersectsPlacedShape for PolygonShape {}
+
+impl IntersectsPosition for PolygonShape {}
+
+impl IntersectsShape for PolygonShape {
+    fn intersects_shape(&self, other_offset: &Position, other_shape: &dyn Shape) -> bool {
+        self.bitmask.intersects_shape(other_offset, other_shape)
+    }
+}
+
+impl IsArea for PolygonShape {}
+
+impl IsSize for PolygonShape {}
+
+impl Placed for PolygonShape {}
+
+impl PlacedObject for PolygonShape {}
+
+impl ProvidesArea for PolygonShape {
+    fn provide_area(&self) -> Area {
+        *self.area()
+    }
+}
+
+impl ProvidesPlacedShape for PolygonShape {
+    fn provide_placed_shape(&self) -> Box<dyn PlacedShape> {
+        Box::new(self.clone())
+    }
+}
+
+impl ProvidesSize for PolygonShape {
+    fn provide_size(&self) -> Size {
+        *self.size()
+    }
+}
+
+impl Shape for PolygonShape {
+    fn box_shape_clone(&self) -> Box<dyn Shape> {
+        Box::new((*self).clone())
+    }
+}
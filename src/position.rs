@@ -28,7 +28,7 @@ impl Position {
     /// assert!(pos.x() == 5);
     /// assert!(pos.y() == -3);
     /// ```
-    pub fn new(x: Coord, y: Coord) -> Self {
+    pub const fn new(x: Coord, y: Coord) -> Self {
         Self { x, y }
     }
 
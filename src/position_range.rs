@@ -46,10 +46,7 @@ impl PositionRange {
 }
 
 impl Distribution<Position> for PositionRange {
-    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Position
-    where
-        R: Rng,
-    {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Position {
         // rng.gen<f64>() generates the range [0.0, 1.0), and thus cannot generate 1.0.
         // This is sub-optimal for this use case.
         let i = rng.gen::<u64>() as f64 / 18_446_744_073_709_551_615.0f64;
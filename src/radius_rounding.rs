@@ -0,0 +1,47 @@
+// External includes.
+
+// Standard includes.
+
+// Internal includes.
+use super::Length;
+
+/// Defines which tiles are inside a radius, for shapes such as [`Circle`](struct.Circle.html) which are measured from the center of a tile.
+///
+/// Distances are measured between tile centers. For a radius of `r`, and a tile at a distance of `d` from the center:
+/// ```
+/// # use dungen_minion_geometry::*;
+/// // `RadiusRounding::Inner` keeps only tiles where `d <= r`.
+/// assert!(RadiusRounding::Inner.is_within(5, 4, 3));
+/// assert!(!RadiusRounding::Inner.is_within(5, 5, 1));
+///
+/// // `RadiusRounding::Round` keeps tiles where `d` rounds to at most `r`; `d < r + 0.5`.
+/// assert!(RadiusRounding::Round.is_within(5, 5, 1));
+/// assert!(!RadiusRounding::Round.is_within(5, 4, 4));
+///
+/// // `RadiusRounding::Outer` keeps tiles where `d < r + 1`.
+/// assert!(RadiusRounding::Outer.is_within(5, 4, 4));
+/// assert!(!RadiusRounding::Outer.is_within(5, 5, 4));
+/// ```
+#[derive(Copy, Clone, Debug, Display, Eq, Hash, PartialEq)]
+pub enum RadiusRounding {
+    /// Tiles whose center is no further from the center than the radius.
+    Inner,
+    /// Tiles whose distance from the center rounds to no more than the radius.
+    Round,
+    /// Tiles whose distance from the center is less than one more than the radius.
+    Outer,
+}
+
+impl RadiusRounding {
+    /// Returns true if a tile offset by `x` and `y` tiles from the center is within the given radius.
+    pub fn is_within(self, radius: Length, x: Length, y: Length) -> bool {
+        let radius = radius as u64;
+        let distance_squared = (x as u64 * x as u64) + (y as u64 * y as u64);
+        match self {
+            RadiusRounding::Inner => distance_squared <= radius * radius,
+            // `d < r + 0.5` is `d² < r² + r + 0.25`; `d²` is a whole number.
+            RadiusRounding::Round => distance_squared <= (radius * radius) + radius,
+            RadiusRounding::Outer => distance_squared < (radius + 1) * (radius + 1),
+        }
+    }
+}
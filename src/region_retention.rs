@@ -0,0 +1,16 @@
+// External includes.
+
+// Standard includes.
+
+// Internal includes.
+
+/// Defines which of the separate regions of a generated shape are kept, such as by a [`CaveGenerator`](struct.CaveGenerator.html).
+///
+/// Regions are made of tiles which share a side.
+#[derive(Copy, Clone, Debug, Display, Eq, Hash, PartialEq)]
+pub enum RegionRetention {
+    /// Keep only the region with the most tiles; where regions tie, the one with the top-most, then left-most tile, is kept.
+    Largest,
+    /// Keep every region.
+    All,
+}
@@ -0,0 +1,48 @@
+// External includes.
+
+// Standard includes.
+
+// Internal includes.
+use super::{AsciiPalette, Coord, PlacedShape, Position};
+
+/// Renders a [`PlacedShape`](trait.PlacedShape.html) as ASCII art, using an [`AsciiPalette`](struct.AsciiPalette.html).
+///
+/// One line is rendered for each row of the shape's [`Area`](struct.Area.html), and every line, including the last, ends with `'\n'`. Lines are not trimmed, so the output can be parsed back with [`BitmaskShape::from_ascii`](struct.BitmaskShape.html#method.from_ascii).
+/// ```
+/// # use dungen_minion_geometry::*;
+/// let room = Area::new(Position::new(3, 3), Size::new(5, 4));
+/// assert!(
+///     room.render_ascii(&AsciiPalette::default())
+///         == concat!(
+///             "#####\n",
+///             "#...#\n",
+///             "#...#\n",
+///             "#####\n",
+///         )
+/// );
+///
+/// let oval = Oval::new(Position::new(0, 0), Size::new(7, 5));
+/// let text = oval.render_ascii(&AsciiPalette::default());
+/// assert!(text.lines().count() == 5);
+/// assert!(text.lines().all(|line| line.chars().count() == 7));
+///
+/// // Rendering and parsing round-trip.
+/// let parsed = BitmaskShape::from_ascii(&text, Position::new(0, 0), &AsciiPalette::default()).unwrap();
+/// assert!(parsed.render_ascii(&AsciiPalette::default()) == text);
+/// ```
+pub trait RendersAscii: PlacedShape {
+    /// Returns this instance as ASCII art, drawn with the given `AsciiPalette`.
+    fn render_ascii(&self, palette: &AsciiPalette) -> String {
+        let mut output = String::new();
+        for y in 0..self.height() as Coord {
+            for x in 0..self.width() as Coord {
+                output.push(palette.character(self.contains_local_position(Position::new(x, y))));
+            }
+            output.push('\n');
+        }
+
+        output
+    }
+}
+
+impl<TPlacedShape> RendersAscii for TPlacedShape where TPlacedShape: PlacedShape + ?Sized {}
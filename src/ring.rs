@@ -0,0 +1,250 @@
+// External includes.
+
+// Standard includes.
+
+// Internal includes.
+use super::containment::containment_from_neighbours;
+use super::intersects_shape::rasterized_intersects_shape;
+use super::{
+    Area, Containment, ContainsLocalPosition, ContainsPosition, CountsTiles, HasArea, HasPosition,
+    HasSize, IntersectsLocalPosition, IntersectsPlacedShape, IntersectsPosition, IntersectsShape,
+    IsArea, IsSize, Placed, PlacedObject, PlacedShape, Position, ProvidesArea, ProvidesPlacedShape,
+    ProvidesSize, Shape, Size,
+};
+
+/// Defines a ring; the tiles of an outer [`PlacedShape`](trait.PlacedShape.html) which are not inside an inner `PlacedShape`.
+///
+/// The outer and inner boundaries are typically both [`Area`](struct.Area.html)s, for hollow rooms, or both [`Oval`](struct.Oval.html)s, for moats. The edge tiles of the inner `PlacedShape` are part of the ring, so that there is a wall on both sides of it; a tile is contained only if all eight of its neighbours are part of the ring.
+///
+/// The ring's [`Area`](struct.Area.html) is the outer `PlacedShape`'s `Area`; moving the ring moves both boundaries.
+/// ```
+/// # use dungen_minion_geometry::*;
+/// let hollow_room = Ring::new(
+///     Area::new(Position::new(0, 0), Size::new(9, 7)),
+///     Area::new(Position::new(2, 2), Size::new(5, 3)),
+/// );
+/// assert!(
+///     hollow_room.render_ascii(&AsciiPalette::default())
+///         == concat!(
+///             "#########\n",
+///             "#.......#\n",
+///             "#.#####.#\n",
+///             "#.#   #.#\n",
+///             "#.#####.#\n",
+///             "#.......#\n",
+///             "#########\n",
+///         )
+/// );
+///
+/// // Excluding an area takes the lesser containment, which fills the hole; the ring leaves it empty.
+/// let values: Box<[(Inclusion, Box<dyn PlacedShape>); 2]> = Box::new([
+///     (Inclusion::Include, Box::new(Area::new(Position::new(0, 0), Size::new(9, 7)))),
+///     (Inclusion::Exclude, Box::new(Area::new(Position::new(2, 2), Size::new(5, 3)))),
+/// ]);
+/// let slice = PlacedShapeSlice::new(values);
+/// assert!(slice.contains_position(Position::new(3, 3)) == Containment::Contains);
+/// assert!(hollow_room.contains_position(Position::new(2, 2)) == Containment::Intersects);
+/// assert!(hollow_room.contains_position(Position::new(3, 3)) == Containment::Disjoint);
+/// assert!(hollow_room.contains_position(Position::new(1, 1)) == Containment::Contains);
+///
+/// // A moat around an island.
+/// let moat = Ring::new(
+///     Oval::new(Position::new(0, 0), Size::new(15, 11)),
+///     Oval::new(Position::new(4, 3), Size::new(7, 5)),
+/// );
+/// assert!(moat.contains_position(Position::new(7, 0)) == Containment::Intersects);
+/// assert!(moat.contains_position(Position::new(7, 1)) == Containment::Contains);
+/// assert!(moat.contains_position(Position::new(7, 3)) == Containment::Intersects);
+/// assert!(moat.contains_position(Position::new(7, 5)) == Containment::Disjoint);
+///
+/// // Moving the ring moves both boundaries.
+/// let mut moved = moat.clone();
+/// *moved.position_mut() = Position::new(10, 20);
+/// assert!(*moved.inner().position() == Position::new(14, 23));
+/// for (position, containment) in moat.local_tiles() {
+///     assert!(moved.contains_local_position(position) == containment);
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Ring<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    outer: TPlacedShape,
+    // The inner boundary, placed relative to the position of the outer boundary.
+    local_inner: TPlacedShape,
+}
+
+impl<TPlacedShape: 'static> Ring<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    /// Creates a new `Ring` of the tiles of `outer` which are not inside `inner`.
+    pub fn new(outer: TPlacedShape, inner: TPlacedShape) -> Self {
+        let mut local_inner = inner;
+        *local_inner.position_mut() = *local_inner.position() - *outer.position();
+        Self { outer, local_inner }
+    }
+
+    /// Returns the inner boundary of the ring, placed where the ring is.
+    pub fn inner(&self) -> TPlacedShape {
+        let mut inner = self.local_inner.clone();
+        *inner.position_mut() = *inner.position() + *self.outer.position();
+        inner
+    }
+
+    /// Returns the outer boundary of the ring.
+    pub fn outer(&self) -> &TPlacedShape {
+        &self.outer
+    }
+}
+
+impl<TPlacedShape: 'static> ContainsLocalPosition for Ring<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    fn contains_local_position(&self, position: Position) -> Containment {
+        containment_from_neighbours(position, |position| {
+            self.intersects_local_position(position)
+        })
+    }
+}
+
+impl<TPlacedShape: 'static> ContainsPosition for Ring<TPlacedShape> where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized
+{
+}
+
+impl<TPlacedShape: 'static> CountsTiles for Ring<TPlacedShape> where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized
+{
+}
+
+impl<TPlacedShape: 'static> HasArea for Ring<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    fn area(&self) -> &Area {
+        self.outer.area()
+    }
+
+    fn area_mut(&mut self) -> &mut Area {
+        self.outer.area_mut()
+    }
+}
+
+impl<TPlacedShape: 'static> HasPosition for Ring<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    fn position(&self) -> &Position {
+        self.outer.position()
+    }
+
+    fn position_mut(&mut self) -> &mut Position {
+        self.outer.position_mut()
+    }
+}
+
+impl<TPlacedShape: 'static> HasSize for Ring<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    fn size(&self) -> &Size {
+        self.outer.size()
+    }
+
+    fn size_mut(&mut self) -> &mut Size {
+        self.outer.size_mut()
+    }
+}
+
+impl<TPlacedShape: 'static> IntersectsLocalPosition for Ring<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    fn intersects_local_position(&self, position: Position) -> bool {
+        self.outer.intersects_local_position(position)
+            && self.local_inner.contains_position(position) != Containment::Contains
+    }
+}
+
+impl<TPlacedShape: 'static> IntersectsPlacedShape for Ring<TPlacedShape> where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized
+{
+}
+
+impl<TPlacedShape: 'static> IntersectsPosition for Ring<TPlacedShape> where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized
+{
+}
+
+impl<TPlacedShape: 'static> IntersectsShape for Ring<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    fn intersects_shape(&self, other_offset: &Position, other_shape: &dyn Shape) -> bool {
+        rasterized_intersects_shape(
+            self.area(),
+            |position| self.intersects_position(position),
+            other_offset,
+            other_shape,
+        )
+    }
+}
+
+impl<TPlacedShape: 'static> IsArea for Ring<TPlacedShape> where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized
+{
+}
+
+impl<TPlacedShape: 'static> IsSize for Ring<TPlacedShape> where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized
+{
+}
+
+impl<TPlacedShape: 'static> Placed for Ring<TPlacedShape> where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized
+{
+}
+
+impl<TPlacedShape: 'static> PlacedObject for Ring<TPlacedShape> where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized
+{
+}
+
+impl<TPlacedShape: 'static> ProvidesArea for Ring<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    fn provide_area(&self) -> Area {
+        self.outer.provide_area()
+    }
+}
+
+impl<TPlacedShape: 'static> ProvidesPlacedShape for Ring<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    fn provide_placed_shape(&self) -> Box<dyn PlacedShape> {
+        Box::new(self.clone())
+    }
+}
+
+impl<TPlacedShape: 'static> ProvidesSize for Ring<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    fn provide_size(&self) -> Size {
+        self.outer.provide_size()
+    }
+}
+
+impl<TPlacedShape: 'static> Shape for Ring<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    fn box_shape_clone(&self) -> Box<dyn Shape> {
+        Box::new((*self).clone())
+    }
+}
@@ -0,0 +1,244 @@
+// External includes.
+
+// Standard includes.
+
+// Internal includes.
+use super::{
+    Area, CardinalDirection, CardinalRotation, Containment, ContainsLocalPosition,
+    ContainsPosition, CountsTiles, HasArea, HasPosition, HasSize, IntersectsLocalPosition,
+    IntersectsPlacedShape, IntersectsPosition, IntersectsShape, IsArea, IsSize, Placed,
+    PlacedObject, PlacedShape, Position, ProvidesArea, ProvidesPlacedShape, ProvidesSize, Shape,
+    Size, TileCount, TransformedShape,
+};
+
+/// Rotates the contained [`PlacedShape`](trait.PlacedShape.html) by a [`CardinalRotation`](enum.CardinalRotation.html) around its bounding [`Area`](struct.Area.html).
+///
+/// A `RotatedShape` is a [`TransformedShape`](struct.TransformedShape.html) which can only be rotated, not mirrored. Local positions are rotated as by `Position * CardinalRotation`, then moved so that the rotated shape keeps the top-left corner of the contained shape's `Area`. The reported [`Size`](struct.Size.html) is the contained shape's `Size * CardinalRotation`.
+/// ```
+/// # use dungen_minion_geometry::*;
+/// // A room with a doorway cut into the middle of its northern wall.
+/// let room = Area::new(Position::new(3, 4), Size::new(5, 3));
+/// let doorway = Area::new(Position::new(4, 3), Size::new(3, 3));
+/// let template = BooleanPlacedShape::new(BooleanOperation::Difference, Box::new(room), Box::new(doorway));
+/// assert!(template.contains_position(Position::new(5, 4)) == Containment::Disjoint);
+///
+/// let east_facing =
+///     RotatedShape::facing(template.clone(), CardinalDirection::North, CardinalDirection::East);
+/// assert!(*east_facing.position() == Position::new(3, 4));
+/// assert!(*east_facing.size() == Size::new(3, 5));
+/// assert!(east_facing.rotation() == CardinalRotation::Left90);
+/// assert!(east_facing.contains_position(Position::new(5, 6)) == Containment::Disjoint);
+/// assert!(east_facing.contains_position(Position::new(3, 6)) == Containment::Intersects);
+///
+/// let west_facing =
+///     RotatedShape::facing(template.clone(), CardinalDirection::North, CardinalDirection::West);
+/// assert!(west_facing.contains_position(Position::new(3, 6)) == Containment::Disjoint);
+///
+/// let south_facing =
+///     RotatedShape::facing(template.clone(), CardinalDirection::North, CardinalDirection::South);
+/// assert!(*south_facing.size() == Size::new(5, 3));
+/// assert!(south_facing.contains_position(Position::new(5, 6)) == Containment::Disjoint);
+///
+/// // Every tile of the rotated shape matches a tile of the contained shape.
+/// let oval = Oval::new(Position::new(0, 0), Size::new(9, 5));
+/// let rotated = RotatedShape::new(oval, CardinalRotation::Right90);
+/// for (position, containment) in oval.local_tiles() {
+///     let rotated_position = (position * CardinalRotation::Right90) + Position::new(0, 8);
+///     assert!(rotated.contains_local_position(rotated_position) == containment);
+/// }
+/// assert!(rotated.local_tiles().count() == oval.local_tiles().count());
+///
+/// // Rotating a rotated shape back restores the original.
+/// let restored = RotatedShape::new(rotated, CardinalRotation::Left90);
+/// for y in -1..=5 {
+///     for x in -1..=9 {
+///         let position = Position::new(x, y);
+///         assert!(restored.contains_position(position) == oval.contains_position(position));
+///     }
+/// }
+/// ```
+#[derive(Clone)]
+pub struct RotatedShape<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    transformed: TransformedShape<TPlacedShape>,
+}
+
+impl<TPlacedShape: 'static> RotatedShape<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    /// Creates a new `RotatedShape` out of the inner `PlacedShape`, rotated by the given `CardinalRotation`.
+    pub fn new(inner: TPlacedShape, rotation: CardinalRotation) -> Self {
+        Self {
+            transformed: TransformedShape::new(inner, rotation.into()),
+        }
+    }
+
+    /// Creates a new `RotatedShape` out of the inner `PlacedShape`, such that what faced in the direction `from` in the inner `PlacedShape` faces in the direction `to`.
+    pub fn facing(inner: TPlacedShape, from: CardinalDirection, to: CardinalDirection) -> Self {
+        // `Position * CardinalRotation` turns the opposite way to `CardinalDirection + CardinalRotation`.
+        Self::new(inner, from - to)
+    }
+
+    /// Returns the inner `PlacedShape`.
+    pub fn inner(&self) -> &TPlacedShape {
+        self.transformed.inner()
+    }
+
+    /// Returns the `CardinalRotation` applied to the inner `PlacedShape`.
+    pub fn rotation(&self) -> CardinalRotation {
+        self.transformed.transform().rotation()
+    }
+}
+
+impl<TPlacedShape: 'static> ContainsLocalPosition for RotatedShape<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    fn contains_local_position(&self, position: Position) -> Containment {
+        self.transformed.contains_local_position(position)
+    }
+}
+
+impl<TPlacedShape: 'static> ContainsPosition for RotatedShape<TPlacedShape> where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized
+{
+}
+
+impl<TPlacedShape: 'static> CountsTiles for RotatedShape<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    fn count_tiles(&self) -> TileCount {
+        self.transformed.count_tiles()
+    }
+}
+
+impl<TPlacedShape: 'static> HasArea for RotatedShape<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    fn area(&self) -> &Area {
+        self.transformed.area()
+    }
+
+    fn area_mut(&mut self) -> &mut Area {
+        self.transformed.area_mut()
+    }
+}
+
+impl<TPlacedShape: 'static> HasPosition for RotatedShape<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    fn position(&self) -> &Position {
+        self.transformed.position()
+    }
+
+    fn position_mut(&mut self) -> &mut Position {
+        self.transformed.position_mut()
+    }
+}
+
+impl<TPlacedShape: 'static> HasSize for RotatedShape<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    fn size(&self) -> &Size {
+        self.transformed.size()
+    }
+
+    fn size_mut(&mut self) -> &mut Size {
+        self.transformed.size_mut()
+    }
+}
+
+impl<TPlacedShape: 'static> IntersectsLocalPosition for RotatedShape<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    fn intersects_local_position(&self, position: Position) -> bool {
+        self.transformed.intersects_local_position(position)
+    }
+}
+
+impl<TPlacedShape: 'static> IntersectsPlacedShape for RotatedShape<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    fn intersects_placed_shape(&self, placed_shape: &dyn PlacedShape) -> bool {
+        self.transformed.intersects_placed_shape(placed_shape)
+    }
+}
+
+impl<TPlacedShape: 'static> IntersectsPosition for RotatedShape<TPlacedShape> where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized
+{
+}
+
+impl<TPlacedShape: 'static> IntersectsShape for RotatedShape<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    fn intersects_shape(&self, other_offset: &Position, other_shape: &dyn Shape) -> bool {
+        self.transformed.intersects_shape(other_offset, other_shape)
+    }
+}
+
+impl<TPlacedShape: 'static> IsArea for RotatedShape<TPlacedShape> where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized
+{
+}
+
+impl<TPlacedShape: 'static> IsSize for RotatedShape<TPlacedShape> where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized
+{
+}
+
+impl<TPlacedShape: 'static> Placed for RotatedShape<TPlacedShape> where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized
+{
+}
+
+impl<TPlacedShape: 'static> PlacedObject for RotatedShape<TPlacedShape> where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized
+{
+}
+
+impl<TPlacedShape: 'static> ProvidesArea for RotatedShape<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    fn provide_area(&self) -> Area {
+        *self.area()
+    }
+}
+
+impl<TPlacedShape: 'static> ProvidesPlacedShape for RotatedShape<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    fn provide_placed_shape(&self) -> Box<dyn PlacedShape> {
+        Box::new(self.clone())
+    }
+}
+
+impl<TPlacedShape: 'static> ProvidesSize for RotatedShape<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    fn provide_size(&self) -> Size {
+        *self.size()
+    }
+}
+
+impl<TPlacedShape: 'static> Shape for RotatedShape<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    fn box_shape_clone(&self) -> Box<dyn Shape> {
+        Box::new((*self).clone())
+    }
+}
@@ -0,0 +1,283 @@
+// External includes.
+
+// Standard includes.
+
+// Internal includes.
+use super::containment::containment_from_neighbours;
+use super::intersects_shape::rasterized_intersects_shape;
+use super::{
+    Area, CardinalDirection, Containment, ContainsLocalPosition, ContainsPosition, Coord,
+    CountsTiles, HasArea, HasBottom, HasPosition, HasRight, HasSize, IntersectsLocalPosition,
+    IntersectsPlacedShape, IntersectsPosition, IntersectsShape, IsArea, IsPosition, IsSize, Length,
+    Placed, PlacedObject, PlacedShape, Position, ProvidesArea, ProvidesPlacedShape, ProvidesSize,
+    RadiusRounding, Shape, Size,
+};
+
+/// Defines a sector of a circle; a cone spreading out from an origin [`Position`](struct.Position.html) in the direction it faces.
+///
+/// A tile is in the sector if it is within the range of the origin, as by [`RadiusRounding::Round`](enum.RadiusRounding.html), and the angle between the facing and the direction from the origin to the tile is no more than the half-angle. The origin tile is always in the sector. Angles are measured in degrees, clockwise from north; tiles exactly on the edge of the angle are included. A half-angle of 180 degrees or more is a whole circle, and a negative half-angle is treated as zero.
+///
+/// The bounding [`Area`](struct.Area.html) is the smallest `Area` which holds every tile of the sector, and so depends on the facing. A tile is contained only if all eight of its neighbours intersect.
+/// ```
+/// # use dungen_minion_geometry::*;
+/// let breath = Sector::new(Position::new(10, 10), CardinalDirection::North, 45.0, 4);
+/// assert!(*breath.area() == Area::new(Position::new(7, 6), Size::new(7, 5)));
+/// assert!(breath.origin() == Position::new(10, 10));
+/// assert!(
+///     breath.render_ascii(&AsciiPalette::default())
+///         == concat!(
+///             " ##### \n",
+///             "##...##\n",
+///             " ##.## \n",
+///             "  ###  \n",
+///             "   #   \n",
+///         )
+/// );
+///
+/// // The same cone, facing east.
+/// let east = Sector::new(Position::new(10, 10), CardinalDirection::East, 45.0, 4);
+/// assert!(*east.area() == Area::new(Position::new(10, 7), Size::new(5, 7)));
+///
+/// // Finer facings are measured in degrees clockwise from north.
+/// let flashlight = Sector::with_angle(Position::new(0, 0), 45.0, 20.0, 6);
+/// assert!(flashlight.intersects_position(Position::new(3, -3)));
+/// assert!(!flashlight.intersects_position(Position::new(0, -3)));
+/// assert!(!flashlight.intersects_position(Position::new(3, 0)));
+///
+/// // A negative half-angle is a half-angle of zero; a single line of tiles.
+/// let needle = Sector::new(Position::new(0, 0), CardinalDirection::North, -10.0, 5);
+/// assert!(needle.half_angle() == 0.0);
+/// assert!(*needle.area() == Area::new(Position::new(0, -5), Size::new(1, 6)));
+/// assert!(needle.tiles().count() == 6);
+///
+/// // Rotating a sector produces the same tiles as rotating its facing.
+/// let directions = [
+///     CardinalDirection::North,
+///     CardinalDirection::East,
+///     CardinalDirection::South,
+///     CardinalDirection::West,
+/// ];
+/// for half_angle in [0.0, 10.0, 30.0, 45.0, 60.0, 90.0, 135.0, 180.0].iter() {
+///     for range in 0..10 {
+///         let north = Sector::new(Position::new(3, -2), CardinalDirection::North, *half_angle, range);
+///         for direction in directions.iter() {
+///             let rotated = RotatedShape::facing(north, CardinalDirection::North, *direction);
+///             let sector = Sector::new(Position::new(3, -2), *direction, *half_angle, range);
+///             assert!(*rotated.size() == *sector.size());
+///             assert!(rotated.local_tiles().eq(sector.local_tiles()));
+///         }
+///     }
+/// }
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Sector {
+    area: Area,
+    local_origin: Position,
+    facing_angle: f64,
+    half_angle: f64,
+    range: Length,
+}
+
+impl Sector {
+    /// Creates a new `Sector` spreading out from the origin towards the given `CardinalDirection`.
+    ///
+    /// The half-angle is measured in degrees, and the range in tiles.
+    pub fn new(
+        origin: Position,
+        facing: CardinalDirection,
+        half_angle: f64,
+        range: Length,
+    ) -> Self {
+        Self::with_angle(origin, i8::from(facing) as f64 * 90.0, half_angle, range)
+    }
+
+    /// Creates a new `Sector` spreading out from the origin towards the given angle.
+    ///
+    /// Angles are measured in degrees, clockwise from north, and the range in tiles. A negative half-angle is treated as zero.
+    pub fn with_angle(origin: Position, facing_angle: f64, half_angle: f64, range: Length) -> Self {
+        let mut output = Self {
+            area: Area::new(Position::zero(), Size::zero()),
+            local_origin: Position::zero(),
+            facing_angle,
+            half_angle: half_angle.max(0.0),
+            range,
+        };
+
+        // Find the smallest area which holds every tile of the sector.
+        let reach = range as Coord;
+        let mut left = Coord::MAX;
+        let mut top = Coord::MAX;
+        let mut right = Coord::MIN;
+        let mut bottom = Coord::MIN;
+        for y in -reach..=reach {
+            for x in -reach..=reach {
+                if output.includes_offset(Position::new(x, y)) {
+                    left = left.min(x);
+                    top = top.min(y);
+                    right = right.max(x);
+                    bottom = bottom.max(y);
+                }
+            }
+        }
+
+        let mut area = Area::new(origin + Position::new(left, top), Size::zero());
+        area.right_set(origin.x() + right);
+        area.bottom_set(origin.y() + bottom);
+        output.area = area;
+        output.local_origin = Position::new(-left, -top);
+        output
+    }
+
+    /// Returns the angle the sector faces, in degrees clockwise from north.
+    pub fn facing_angle(&self) -> f64 {
+        self.facing_angle
+    }
+
+    /// Returns the largest angle between the facing and a tile in the sector, in degrees.
+    pub fn half_angle(&self) -> f64 {
+        self.half_angle
+    }
+
+    /// Returns the `Position` the sector spreads out from.
+    pub fn origin(&self) -> Position {
+        *self.position() + self.local_origin
+    }
+
+    /// Returns the range of the sector, in tiles.
+    pub fn range(&self) -> Length {
+        self.range
+    }
+
+    /// Returns the unit vector of the facing, with north as `(0, -1)`.
+    ///
+    /// Facings along the axes are exact, so that sectors facing each `CardinalDirection` are rotations of each other.
+    fn facing_vector(&self) -> (f64, f64) {
+        let angle = self.facing_angle.rem_euclid(360.0);
+        if angle == 0.0 {
+            (0.0, -1.0)
+        } else if angle == 90.0 {
+            (1.0, 0.0)
+        } else if angle == 180.0 {
+            (0.0, 1.0)
+        } else if angle == 270.0 {
+            (-1.0, 0.0)
+        } else {
+            let radians = angle.to_radians();
+            (radians.sin(), -radians.cos())
+        }
+    }
+
+    /// Returns whether the tile at the given offset from the origin is in the sector.
+    fn includes_offset(&self, offset: Position) -> bool {
+        let x = offset.x().unsigned_abs();
+        let y = offset.y().unsigned_abs();
+        if x > self.range || y > self.range || !RadiusRounding::Round.is_within(self.range, x, y) {
+            return false;
+        }
+        if offset == Position::zero() || self.half_angle >= 180.0 {
+            return true;
+        }
+
+        let (facing_x, facing_y) = self.facing_vector();
+        let dot = (offset.x() as f64 * facing_x) + (offset.y() as f64 * facing_y);
+        let distance = ((offset.x() as f64).powi(2) + (offset.y() as f64).powi(2)).sqrt();
+        // A small tolerance keeps tiles exactly on the edge of the angle.
+        dot >= distance * (self.half_angle.to_radians().cos() - 1e-9)
+    }
+}
+
+impl ContainsLocalPosition for Sector {
+    fn contains_local_position(&self, position: Position) -> Containment {
+        containment_from_neighbours(position, |position| {
+            self.intersects_local_position(position)
+        })
+    }
+}
+
+impl ContainsPosition for Sector {}
+
+impl CountsTiles for Sector {}
+
+impl HasArea for Sector {
+    fn area(&self) -> &Area {
+        &self.area
+    }
+
+    fn area_mut(&mut self) -> &mut Area {
+        &mut self.area
+    }
+}
+
+impl HasPosition for Sector {
+    fn position(&self) -> &Position {
+        self.area.position()
+    }
+
+    fn position_mut(&mut self) -> &mut Position {
+        self.area.position_mut()
+    }
+}
+
+impl HasSize for Sector {
+    fn size(&self) -> &Size {
+        self.area.size()
+    }
+
+    fn size_mut(&mut self) -> &mut Size {
+        self.area.size_mut()
+    }
+}
+
+impl IntersectsLocalPosition for Sector {
+    fn intersects_local_position(&self, position: Position) -> bool {
+        self.area.intersects_local_position(position)
+            && self.includes_offset(position - self.local_origin)
+    }
+}
+
+impl IntersectsPlacedShape for Sector {}
+
+impl IntersectsPosition for Sector {}
+
+impl IntersectsShape for Sector {
+    fn intersects_shape(&self, other_offset: &Position, other_shape: &dyn Shape) -> bool {
+        rasterized_intersects_shape(
+            self.area(),
+            |position| self.intersects_position(position),
+            other_offset,
+            other_shape,
+        )
+    }
+}
+
+impl IsArea for Sector {}
+
+impl IsSize for Sector {}
+
+impl Placed for Sector {}
+
+impl PlacedObject for Sector {}
+
+impl ProvidesArea for Sector {
+    fn provide_area(&self) -> Area {
+        *self.area()
+    }
+}
+
+impl ProvidesPlacedShape for Sector {
+    fn provide_placed_shape(&self) -> Box<dyn PlacedShape> {
+        Box::new(*self)
+    }
+}
+
+impl ProvidesSize for Sector {
+    fn provide_size(&self) -> Size {
+        *self.area.size()
+    }
+}
+
+impl Shape for Sector {
+    fn box_shape_clone(&self) -> Box<dyn Shape> {
+        Box::new(*self)
+    }
+}
@@ -48,10 +48,7 @@ impl SizeRange {
 }
 
 impl Distribution<Size> for SizeRange {
-    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Size
-    where
-        R: Rng,
-    {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Size {
         Size::new(
             rng.gen_range(self.min_size.width(), self.max_size.width() + 1),
             rng.gen_range(self.min_size.height(), self.max_size.height() + 1),
@@ -0,0 +1,65 @@
+// External includes.
+
+// Standard includes.
+use std::fmt;
+use std::ops::{Add, AddAssign};
+
+// Internal includes.
+
+/// The number of tiles a shape covers, split into the tiles entirely inside the shape, and the tiles on its edge.
+///
+/// ```
+/// # use dungen_minion_geometry::*;
+/// let count = TileCount::new(6, 14);
+/// assert!(count.interior() == 6);
+/// assert!(count.edge() == 14);
+/// assert!(count.total() == 20);
+/// assert!(count + TileCount::new(1, 2) == TileCount::new(7, 16));
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct TileCount {
+    interior: usize,
+    edge: usize,
+}
+
+impl TileCount {
+    /// Creates a new `TileCount` from the number of interior tiles and the number of edge tiles.
+    pub fn new(interior: usize, edge: usize) -> Self {
+        Self { interior, edge }
+    }
+
+    /// The number of tiles which are entirely contained within the shape; [`Containment::Contains`](enum.Containment.html).
+    pub fn interior(&self) -> usize {
+        self.interior
+    }
+
+    /// The number of tiles which are on the edge of the shape; [`Containment::Intersects`](enum.Containment.html).
+    pub fn edge(&self) -> usize {
+        self.edge
+    }
+
+    /// The number of tiles which intersect the shape; the sum of the interior and edge tiles.
+    pub fn total(&self) -> usize {
+        self.interior + self.edge
+    }
+}
+
+impl Add<TileCount> for TileCount {
+    type Output = TileCount;
+
+    fn add(self, other: TileCount) -> Self::Output {
+        Self::new(self.interior + other.interior, self.edge + other.edge)
+    }
+}
+
+impl AddAssign<TileCount> for TileCount {
+    fn add_assign(&mut self, other: TileCount) {
+        *self = *self + other
+    }
+}
+
+impl fmt::Display for TileCount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "( interior: {}, edge: {} )", self.interior, self.edge)
+    }
+}
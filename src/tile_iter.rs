@@ -0,0 +1,75 @@
+// External includes.
+
+// Standard includes.
+
+// Internal includes.
+use super::{Containment, Coord, Position, Shape};
+
+/// An iterator over the tiles of a [`Shape`](trait.Shape.html), yielding each tile's [`Position`](struct.Position.html) and [`Containment`](enum.Containment.html).
+///
+/// Tiles are visited in rows from top to bottom, and from left to right within each row. Only the tiles within the `Shape`'s [`Size`](struct.Size.html) are visited, and disjoint tiles are never yielded.
+///
+/// See [`IteratesLocalTiles`](trait.IteratesLocalTiles.html) and [`IteratesTiles`](trait.IteratesTiles.html).
+pub struct TileIter<'a, TShape>
+where
+    TShape: Shape + ?Sized,
+{
+    shape: &'a TShape,
+    offset: Position,
+    filter: Option<Containment>,
+    x: Coord,
+    y: Coord,
+}
+
+impl<'a, TShape> TileIter<'a, TShape>
+where
+    TShape: Shape + ?Sized,
+{
+    /// Creates a new `TileIter` over the given `Shape`.
+    ///
+    /// Each yielded `Position` is the local `Position` of the tile plus `offset`. If `filter` is `Some`, only tiles with that `Containment` are yielded.
+    pub fn new(shape: &'a TShape, offset: Position, filter: Option<Containment>) -> Self {
+        Self {
+            shape,
+            offset,
+            filter,
+            x: 0,
+            y: 0,
+        }
+    }
+}
+
+impl<'a, TShape> Iterator for TileIter<'a, TShape>
+where
+    TShape: Shape + ?Sized,
+{
+    type Item = (Position, Containment);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let width = self.shape.width() as Coord;
+        let height = self.shape.height() as Coord;
+        if width == 0 {
+            return None;
+        }
+
+        while self.y < height {
+            let position = Position::new(self.x, self.y);
+            self.x += 1;
+            if self.x >= width {
+                self.x = 0;
+                self.y += 1;
+            }
+
+            let containment = self.shape.contains_local_position(position);
+            let is_yielded = match self.filter {
+                Some(filter) => containment == filter,
+                None => containment != Containment::Disjoint,
+            };
+            if is_yielded {
+                return Some((position + self.offset, containment));
+            }
+        }
+
+        None
+    }
+}
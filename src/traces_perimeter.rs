@@ -0,0 +1,183 @@
+// External includes.
+
+// Standard includes.
+
+// Internal includes.
+use super::{Connectivity, Coord, IsPosition, PlacedShape, Position};
+
+/// Traces the perimeter of a [`PlacedShape`](trait.PlacedShape.html) as ordered, closed loops of edge tiles.
+///
+/// One loop is returned for each boundary of the shape; the outer boundary of each separate part of the shape, and the boundary of each hole. Each loop lists its tiles in world coordinates, in walking order, and does not repeat its first tile at the end; the last tile is adjacent to the first. Where the shape is only one tile wide, the loop walks out and back, and so visits those tiles twice.
+///
+/// With [`Connectivity::Four`](enum.Connectivity.html), consecutive tiles share a side, and every tile which touches the outside of the shape is visited. With `Connectivity::Eight`, consecutive tiles may share only a corner, and tiles which touch the outside of the shape only at a corner are skipped.
+///
+/// The shape is traced within its [`Area`](struct.Area.html), and the ring of tiles around it, which are asked of the shape rather than assumed to be outside. Only boundaries which touch a tile the shape does not intersect are returned, so a shape which extends outside of its `Area`, such as an [`InvertPlacedShape`](struct.InvertPlacedShape.html), has no boundary along the edge of its `Area`.
+/// ```
+/// # use dungen_minion_geometry::*;
+/// let room = Area::new(Position::new(2, 3), Size::new(5, 4));
+/// let perimeters = room.trace_perimeters(Connectivity::Four);
+/// assert!(perimeters.len() == 1);
+/// assert!(perimeters[0].len() == 14);
+/// assert!(perimeters[0][0] == Position::new(2, 3));
+/// assert!(perimeters[0]
+///     .iter()
+///     .all(|position| room.contains_position(*position) == Containment::Intersects));
+///
+/// // A room with a pillar has an outer boundary, and a boundary around the pillar.
+/// let room = Area::new(Position::new(0, 0), Size::new(9, 9));
+/// let pillar = Area::new(Position::new(2, 2), Size::new(5, 5));
+/// let room_with_pillar =
+///     BooleanPlacedShape::new(BooleanOperation::Difference, Box::new(room), Box::new(pillar));
+/// let perimeters = room_with_pillar.trace_perimeters(Connectivity::Four);
+/// assert!(perimeters.len() == 2);
+/// assert!(perimeters[0].len() == 32);
+/// assert!(perimeters[1].len() == 16);
+///
+/// // With eight-connectivity, the corners of the pillar only touch its interior diagonally.
+/// let perimeters = room_with_pillar.trace_perimeters(Connectivity::Eight);
+/// assert!(perimeters.len() == 2);
+/// assert!(perimeters[0].len() == 32);
+/// assert!(perimeters[1].len() == 12);
+///
+/// // An inverted room has only the boundary around the room's interior.
+/// let inverted_room = InvertPlacedShape::new(Area::new(Position::new(0, 0), Size::new(5, 5)));
+/// let perimeters = inverted_room.trace_perimeters(Connectivity::Four);
+/// assert!(perimeters.len() == 1);
+/// assert!(perimeters[0].len() == 16);
+/// assert!(perimeters[0]
+///     .iter()
+///     .all(|position| inverted_room.contains_position(*position) == Containment::Intersects));
+///
+/// // Every loop is closed, and made of adjacent edge tiles.
+/// let oval = Oval::new(Position::new(-10, 4), Size::new(17, 11));
+/// for connectivity in [Connectivity::Four, Connectivity::Eight].iter() {
+///     for perimeter in oval.trace_perimeters(*connectivity) {
+///         for i in 0..perimeter.len() {
+///             let offset = perimeter[(i + 1) % perimeter.len()] - perimeter[i];
+///             assert!(connectivity.offsets().contains(&offset));
+///             assert!(oval.contains_position(perimeter[i]) == Containment::Intersects);
+///         }
+///     }
+/// }
+/// ```
+pub trait TracesPerimeter: PlacedShape {
+    /// Returns one ordered, closed loop of edge tiles for each boundary of this instance.
+    fn trace_perimeters(&self, connectivity: Connectivity) -> Vec<Vec<Position>> {
+        let width = self.width() as usize + 4;
+        let height = self.height() as usize + 4;
+        // The labelled tiles of the shape and the ring of tiles around it, surrounded by a frame of tiles treated as outside of the shape.
+        let mut labels = vec![0i32; width * height];
+        for y in 1..(height - 1) {
+            for x in 1..(width - 1) {
+                if self.intersects_local_position(Position::new(x as Coord - 2, y as Coord - 2)) {
+                    labels[(y * width) + x] = 1;
+                }
+            }
+        }
+
+        let offsets = connectivity.offsets();
+        let index = |position: Position| (position.y() as usize * width) + position.x() as usize;
+        let direction_of = |offset: Position| {
+            offsets
+                .iter()
+                .position(|other_offset| *other_offset == offset)
+                .unwrap()
+        };
+        let to_world = |position: Position| {
+            Position::new(
+                position.x() + self.left() - 2,
+                position.y() + self.top() - 2,
+            )
+        };
+        // Whether a tile touches a tile the shape does not intersect, other than one of the frame.
+        let is_on_boundary = |labels: &[i32], position: Position| {
+            Connectivity::Eight.offsets().iter().any(|offset| {
+                let neighbour = position + *offset;
+                neighbour.x() > 0
+                    && neighbour.y() > 0
+                    && neighbour.x() < width as Coord - 1
+                    && neighbour.y() < height as Coord - 1
+                    && labels[index(neighbour)] == 0
+            })
+        };
+
+        // Border following, after Suzuki and Abe (1985).
+        let mut perimeters = Vec::new();
+        let mut border_label = 1;
+        for y in 1..(height as Coord - 1) {
+            for x in 1..(width as Coord - 1) {
+                let start = Position::new(x, y);
+                let start_label = labels[index(start)];
+                let previous = if start_label == 1 && labels[index(start + Position::WEST)] == 0 {
+                    start + Position::WEST
+                } else if start_label >= 1 && labels[index(start + Position::EAST)] == 0 {
+                    start + Position::EAST
+                } else {
+                    continue;
+                };
+                border_label += 1;
+
+                // Look clockwise around the start for the last tile of the border.
+                let first_direction = direction_of(previous - start);
+                let last = (1..=offsets.len())
+                    .map(|i| start + offsets[(first_direction + offsets.len() - i) % offsets.len()])
+                    .find(|position| labels[index(*position)] != 0);
+                let last = match last {
+                    Some(last) => last,
+                    None => {
+                        labels[index(start)] = -border_label;
+                        if is_on_boundary(&labels, start) {
+                            perimeters.push(vec![to_world(start)]);
+                        }
+                        continue;
+                    }
+                };
+
+                let mut perimeter = Vec::new();
+                let mut is_boundary = false;
+                let mut previous = last;
+                let mut current = start;
+                loop {
+                    perimeter.push(to_world(current));
+                    is_boundary = is_boundary || is_on_boundary(&labels, current);
+
+                    // Look counter-clockwise around the current tile for the next tile of the border.
+                    let previous_direction = direction_of(previous - current);
+                    let mut is_east_outside = false;
+                    let mut next = previous;
+                    for i in 1..=offsets.len() {
+                        let direction = (previous_direction + i) % offsets.len();
+                        let position = current + offsets[direction];
+                        if labels[index(position)] != 0 {
+                            next = position;
+                            break;
+                        } else if direction == 0 {
+                            is_east_outside = true;
+                        }
+                    }
+
+                    if is_east_outside {
+                        labels[index(current)] = -border_label;
+                    } else if labels[index(current)] == 1 {
+                        labels[index(current)] = border_label;
+                    }
+
+                    if next == start && current == last {
+                        break;
+                    }
+                    previous = current;
+                    current = next;
+                }
+
+                // A border which only touches the frame is not a boundary of the shape.
+                if is_boundary {
+                    perimeters.push(perimeter);
+                }
+            }
+        }
+
+        perimeters
+    }
+}
+
+impl<TPlacedShape> TracesPerimeter for TPlacedShape where TPlacedShape: PlacedShape + ?Sized {}
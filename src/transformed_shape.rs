@@ -0,0 +1,248 @@
+// External includes.
+
+// Standard includes.
+
+// Internal includes.
+use super::intersects_shape::rasterized_intersects_shape;
+use super::{
+    Area, CardinalTransform, Containment, ContainsLocalPosition, ContainsPosition, CountsTiles,
+    HasArea, HasPosition, HasSize, IntersectsLocalPosition, IntersectsPlacedShape,
+    IntersectsPosition, IntersectsShape, IsArea, IsSize, Placed, PlacedObject, PlacedShape,
+    Position, ProvidesArea, ProvidesPlacedShape, ProvidesSize, Shape, Size, TileCount,
+};
+
+/// Transforms the contained [`PlacedShape`](trait.PlacedShape.html) by a [`CardinalTransform`](struct.CardinalTransform.html) around its bounding [`Area`](struct.Area.html).
+///
+/// Local positions are transformed as by `Position * CardinalTransform`, then moved so that the transformed shape keeps the top-left corner of the contained shape's `Area`. The reported [`Size`](struct.Size.html) is the contained shape's `Size * CardinalTransform`.
+/// ```
+/// # use dungen_minion_geometry::*;
+/// // An L-shaped room.
+/// let west_wing = Area::new(Position::new(0, 0), Size::new(3, 6));
+/// let south_wing = Area::new(Position::new(0, 3), Size::new(7, 3));
+/// let l_shape =
+///     BooleanPlacedShape::new(BooleanOperation::Union, Box::new(west_wing), Box::new(south_wing));
+/// assert!(l_shape.intersects_position(Position::new(1, 0)));
+/// assert!(!l_shape.intersects_position(Position::new(5, 0)));
+///
+/// let mirrored = TransformedShape::new(l_shape.clone(), CardinalTransform::MIRROR_HORIZONTAL);
+/// assert!(*mirrored.area() == *l_shape.area());
+/// assert!(!mirrored.intersects_position(Position::new(1, 0)));
+/// assert!(mirrored.intersects_position(Position::new(5, 0)));
+///
+/// let flipped = TransformedShape::new(l_shape.clone(), CardinalTransform::MIRROR_VERTICAL);
+/// assert!(flipped.intersects_position(Position::new(1, 5)));
+/// assert!(!flipped.intersects_position(Position::new(5, 5)));
+/// assert!(flipped.intersects_position(Position::new(5, 0)));
+///
+/// // Each of the eight transforms matches the tiles of the contained shape one-to-one.
+/// for transform in CardinalTransform::all().iter() {
+///     let transformed = TransformedShape::new(l_shape.clone(), *transform);
+///     assert!(*transformed.size() == *l_shape.size() * *transform);
+///     assert!(transformed.tiles().count() == l_shape.tiles().count());
+///     assert!(transformed.edge_tiles().count() == l_shape.edge_tiles().count());
+///
+///     // Transforming by the inverse restores the original.
+///     let restored = TransformedShape::new(transformed, -*transform);
+///     for y in -1..=6 {
+///         for x in -1..=7 {
+///             let position = Position::new(x, y);
+///             assert!(restored.contains_position(position) == l_shape.contains_position(position));
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone)]
+pub struct TransformedShape<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    area: Area,
+    inner: TPlacedShape,
+    transform: CardinalTransform,
+}
+
+impl<TPlacedShape: 'static> TransformedShape<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    /// Creates a new `TransformedShape` out of the inner `PlacedShape`, transformed by the given `CardinalTransform`.
+    pub fn new(inner: TPlacedShape, transform: CardinalTransform) -> Self {
+        Self {
+            area: Area::new(*inner.position(), *inner.size() * transform),
+            inner,
+            transform,
+        }
+    }
+
+    /// Returns the inner `PlacedShape`.
+    pub fn inner(&self) -> &TPlacedShape {
+        &self.inner
+    }
+
+    /// Returns the `CardinalTransform` applied to the inner `PlacedShape`.
+    pub fn transform(&self) -> CardinalTransform {
+        self.transform
+    }
+
+    /// Maps a local `Position` of this instance to the matching local `Position` of the inner `PlacedShape`.
+    fn inner_local_position(&self, position: Position) -> Position {
+        let inner_local_area = Area::new(Position::zero(), *self.inner.size());
+        let offset = *(inner_local_area * self.transform).position();
+
+        (position + offset) * self.transform.inverse()
+    }
+}
+
+impl<TPlacedShape: 'static> ContainsLocalPosition for TransformedShape<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    fn contains_local_position(&self, position: Position) -> Containment {
+        self.inner
+            .contains_local_position(self.inner_local_position(position))
+    }
+}
+
+impl<TPlacedShape: 'static> ContainsPosition for TransformedShape<TPlacedShape> where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized
+{
+}
+
+impl<TPlacedShape: 'static> CountsTiles for TransformedShape<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    fn count_tiles(&self) -> TileCount {
+        // Every tile maps to exactly one tile of the inner shape.
+        self.inner.count_tiles()
+    }
+}
+
+impl<TPlacedShape: 'static> HasArea for TransformedShape<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    fn area(&self) -> &Area {
+        &self.area
+    }
+
+    fn area_mut(&mut self) -> &mut Area {
+        &mut self.area
+    }
+}
+
+impl<TPlacedShape: 'static> HasPosition for TransformedShape<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    fn position(&self) -> &Position {
+        self.area.position()
+    }
+
+    fn position_mut(&mut self) -> &mut Position {
+        self.area.position_mut()
+    }
+}
+
+impl<TPlacedShape: 'static> HasSize for TransformedShape<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    fn size(&self) -> &Size {
+        self.area.size()
+    }
+
+    fn size_mut(&mut self) -> &mut Size {
+        self.area.size_mut()
+    }
+}
+
+impl<TPlacedShape: 'static> IntersectsLocalPosition for TransformedShape<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    fn intersects_local_position(&self, position: Position) -> bool {
+        self.inner
+            .intersects_local_position(self.inner_local_position(position))
+    }
+}
+
+impl<TPlacedShape: 'static> IntersectsPlacedShape for TransformedShape<TPlacedShape> where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized
+{
+}
+
+impl<TPlacedShape: 'static> IntersectsPosition for TransformedShape<TPlacedShape> where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized
+{
+}
+
+impl<TPlacedShape: 'static> IntersectsShape for TransformedShape<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    fn intersects_shape(&self, other_offset: &Position, other_shape: &dyn Shape) -> bool {
+        rasterized_intersects_shape(
+            self.area(),
+            |position| self.intersects_position(position),
+            other_offset,
+            other_shape,
+        )
+    }
+}
+
+impl<TPlacedShape: 'static> IsArea for TransformedShape<TPlacedShape> where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized
+{
+}
+
+impl<TPlacedShape: 'static> IsSize for TransformedShape<TPlacedShape> where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized
+{
+}
+
+impl<TPlacedShape: 'static> Placed for TransformedShape<TPlacedShape> where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized
+{
+}
+
+impl<TPlacedShape: 'static> PlacedObject for TransformedShape<TPlacedShape> where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized
+{
+}
+
+impl<TPlacedShape: 'static> ProvidesArea for TransformedShape<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    fn provide_area(&self) -> Area {
+        *self.area()
+    }
+}
+
+impl<TPlacedShape: 'static> ProvidesPlacedShape for TransformedShape<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    fn provide_placed_shape(&self) -> Box<dyn PlacedShape> {
+        Box::new(self.clone())
+    }
+}
+
+impl<TPlacedShape: 'static> ProvidesSize for TransformedShape<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    fn provide_size(&self) -> Size {
+        *self.area.size()
+    }
+}
+
+impl<TPlacedShape: 'static> Shape for TransformedShape<TPlacedShape>
+where
+    TPlacedShape: Clone + PlacedShape + ProvidesArea + ProvidesSize + Sized,
+{
+    fn box_shape_clone(&self) -> Box<dyn Shape> {
+        Box::new((*self).clone())
+    }
+}
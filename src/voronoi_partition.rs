@@ -0,0 +1,210 @@
+// External includes.
+use rand::distributions::Distribution;
+use rand::Rng;
+
+// Standard includes.
+
+// Internal includes.
+use super::{
+    Area, BitmaskShape, Containment, Coord, Count, DistanceMetric, HasBottom, HasHeight,
+    HasPosition, HasRight, HasWidth, IntersectsPosition, IsPosition, Position, ProvidesPosition,
+    Size,
+};
+
+/// Partitions an [`Area`](struct.Area.html) into regions, one around each of a list of seed [`Position`](struct.Position.html)s.
+///
+/// Each tile of the `Area` belongs to the region of the seed nearest to it, as measured by a [`DistanceMetric`](enum.DistanceMetric.html); where seeds are equally near, the tile belongs to the region of the seed which comes first. Each region is a [`BitmaskShape`](struct.BitmaskShape.html) over the smallest `Area` holding its tiles. A tile is contained only if all eight of its neighbours are in the same region, so the borders between regions report [`Containment::Intersects`](enum.Containment.html), and walls appear on both sides of every border.
+/// ```
+/// # use dungen_minion_geometry::*;
+/// use rand::SeedableRng;
+/// use rand_chacha::ChaCha8Rng;
+///
+/// let area = Area::new(Position::new(0, 0), Size::new(10, 5));
+/// let partition = VoronoiPartition::new(
+///     area,
+///     &[Position::new(2, 2), Position::new(7, 2)],
+///     DistanceMetric::Euclidean,
+/// );
+/// let regions = partition.regions();
+/// assert!(regions.len() == 2);
+/// assert!(*regions[0].area() == Area::new(Position::new(0, 0), Size::new(5, 5)));
+/// assert!(*regions[1].area() == Area::new(Position::new(5, 0), Size::new(5, 5)));
+/// assert!(regions[0].contains_position(Position::new(3, 2)) == Containment::Contains);
+/// assert!(regions[0].contains_position(Position::new(4, 2)) == Containment::Intersects);
+/// assert!(regions[1].contains_position(Position::new(5, 2)) == Containment::Intersects);
+/// assert!(partition.region_at(Position::new(8, 1)) == Some(1));
+/// assert!(partition.region_at(Position::new(10, 1)) == None);
+///
+/// // Every tile of the area belongs to exactly one region, whichever metric is used.
+/// let seeds = [Position::new(3, 3), Position::new(25, 5), Position::new(12, 17), Position::new(-4, 12)];
+/// let area = Area::new(Position::new(0, 0), Size::new(30, 20));
+/// for metric in [DistanceMetric::Manhattan, DistanceMetric::Chebyshev, DistanceMetric::Euclidean].iter() {
+///     let partition = VoronoiPartition::new(area, &seeds, *metric);
+///     for (position, _) in area.tiles() {
+///         let owners: Vec<usize> = partition
+///             .regions()
+///             .iter()
+///             .enumerate()
+///             .filter(|(_, region)| region.intersects_position(position))
+///             .map(|(index, _)| index)
+///             .collect();
+///         assert!(owners == vec![partition.region_at(position).unwrap()]);
+///         let owner = &seeds[owners[0]];
+///         assert!(seeds.iter().all(|seed| metric.distance(position, *owner) <= metric.distance(position, *seed)));
+///     }
+/// }
+///
+/// // Seeds can be provided, such as by a `PositionRange`.
+/// let seeds = PositionRange::new(Position::new(0, 0), Position::new(29, 19));
+/// let partition = VoronoiPartition::with_provided_seeds(area, 6, &seeds, DistanceMetric::Manhattan);
+/// assert!(partition.seeds().len() == 6);
+/// let tile_count: usize = partition.regions().iter().map(|region| region.count_tiles().total()).sum();
+/// assert!(tile_count == 600);
+///
+/// // Seeds sampled with a seeded `Rng` give the same partition every time, on every platform.
+/// let partition = VoronoiPartition::with_sampled_seeds(
+///     area,
+///     6,
+///     &seeds,
+///     &mut ChaCha8Rng::seed_from_u64(3),
+///     DistanceMetric::Euclidean,
+/// );
+/// let again = VoronoiPartition::with_sampled_seeds(
+///     area,
+///     6,
+///     &seeds,
+///     &mut ChaCha8Rng::seed_from_u64(3),
+///     DistanceMetric::Euclidean,
+/// );
+/// assert!(partition.seeds() == again.seeds());
+/// assert!(partition.seeds().iter().all(|seed| area.intersects_position(*seed)));
+/// ```
+#[derive(Clone, Debug)]
+pub struct VoronoiPartition {
+    area: Area,
+    seeds: Vec<Position>,
+    metric: DistanceMetric,
+    regions: Vec<BitmaskShape>,
+    // The index of the region of each tile of the area, stored row by row.
+    owners: Vec<usize>,
+}
+
+impl VoronoiPartition {
+    /// Creates a new `VoronoiPartition` of the `Area`, with a region around each of the seeds.
+    ///
+    /// Seeds may be outside of the `Area`; a seed with no tiles nearest to it has a region with no tiles.
+    pub fn new(area: Area, seeds: &[Position], metric: DistanceMetric) -> Self {
+        let width = area.width() as usize;
+        let height = area.height() as usize;
+        let mut owners = vec![0; width * height];
+        let mut region_tiles = vec![Vec::new(); seeds.len()];
+        if !seeds.is_empty() {
+            for y in 0..height {
+                for x in 0..width {
+                    let position = *area.position() + Position::new(x as Coord, y as Coord);
+                    let mut owner = 0;
+                    let mut owner_distance = metric.distance(position, seeds[0]);
+                    for (index, seed) in seeds.iter().enumerate().skip(1) {
+                        let distance = metric.distance(position, *seed);
+                        if distance < owner_distance {
+                            owner = index;
+                            owner_distance = distance;
+                        }
+                    }
+                    owners[(y * width) + x] = owner;
+                    region_tiles[owner].push(position);
+                }
+            }
+        }
+
+        let regions = region_tiles
+            .iter()
+            .zip(seeds.iter())
+            .map(|(tiles, seed)| {
+                if tiles.is_empty() {
+                    return BitmaskShape::new(Area::new(*seed, Size::zero()));
+                }
+
+                let left = tiles.iter().map(|tile| tile.x()).min().unwrap();
+                let top = tiles.iter().map(|tile| tile.y()).min().unwrap();
+                let mut region_area = Area::new(Position::new(left, top), Size::zero());
+                region_area.right_set(tiles.iter().map(|tile| tile.x()).max().unwrap());
+                region_area.bottom_set(tiles.iter().map(|tile| tile.y()).max().unwrap());
+
+                let mut region = BitmaskShape::new(region_area);
+                for tile in tiles.iter() {
+                    region.set_containment(*tile, Containment::Intersects);
+                }
+                region.recalculate_containment();
+                region
+            })
+            .collect();
+
+        Self {
+            area,
+            seeds: seeds.to_vec(),
+            metric,
+            regions,
+            owners,
+        }
+    }
+
+    /// Creates a new `VoronoiPartition` of the `Area`, with a region around each of the given number of seeds, which are provided by a `ProvidesPosition`.
+    pub fn with_provided_seeds(
+        area: Area,
+        seed_count: Count,
+        seeds: &dyn ProvidesPosition,
+        metric: DistanceMetric,
+    ) -> Self {
+        let seeds: Vec<Position> = (0..seed_count).map(|_| seeds.provide_position()).collect();
+        Self::new(area, &seeds, metric)
+    }
+
+    /// Creates a new `VoronoiPartition` of the `Area`, with a region around each of the given number of seeds, which are sampled from a `Distribution`, such as a `PositionRange`, with the `Rng`.
+    ///
+    /// The same `Rng` state always gives the same partition.
+    pub fn with_sampled_seeds<TDistribution, R>(
+        area: Area,
+        seed_count: Count,
+        seeds: &TDistribution,
+        rng: &mut R,
+        metric: DistanceMetric,
+    ) -> Self
+    where
+        TDistribution: Distribution<Position>,
+        R: Rng + ?Sized,
+    {
+        let seeds: Vec<Position> = (0..seed_count).map(|_| seeds.sample(rng)).collect();
+        Self::new(area, &seeds, metric)
+    }
+
+    /// Returns the `Area` which is partitioned.
+    pub fn area(&self) -> Area {
+        self.area
+    }
+
+    /// Returns the `DistanceMetric` used to find the nearest seed to each tile.
+    pub fn metric(&self) -> DistanceMetric {
+        self.metric
+    }
+
+    /// Returns the index of the region the tile belongs to, or `None` if the tile is outside of the partitioned `Area`.
+    pub fn region_at(&self, position: Position) -> Option<usize> {
+        if self.seeds.is_empty() || !self.area.intersects_position(position) {
+            return None;
+        }
+
+        let local = position - *self.area.position();
+        Some(self.owners[(local.y() as usize * self.area.width() as usize) + local.x() as usize])
+    }
+
+    /// Returns the regions, in the same order as their seeds.
+    pub fn regions(&self) -> &[BitmaskShape] {
+        &self.regions
+    }
+
+    /// Returns the seed `Position`s.
+    pub fn seeds(&self) -> &[Position] {
+        &self.seeds
+    }
+}